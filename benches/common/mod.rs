@@ -0,0 +1,42 @@
+use std::{
+    hint::black_box,
+    time::{
+        Duration,
+        Instant
+    }
+};
+// A small stand-in for criterion that needs no extra crates: each routine is
+// warmed up, then timed in growing batches until about a second has passed,
+// and the fastest batch is reported per iteration.
+pub fn bench<R, F: FnMut() -> R>(name: &str, mut routine: F) {
+    let filter: Vec<String> = std::env::args().skip(1).filter(|a| !a.starts_with('-')).collect();
+    if !filter.is_empty() && !filter.iter().any(|f| name.contains(f.as_str())) {
+        return;
+    }
+    let warm_up = Instant::now();
+    while warm_up.elapsed() < Duration::from_millis(200) {
+        black_box(routine());
+    }
+    let (mut batch, mut best, mut total) = (1u64, f64::MAX, Duration::from_secs(0));
+    while total < Duration::from_secs(1) {
+        let start = Instant::now();
+        for _ in 0..batch {
+            black_box(routine());
+        }
+        let elapsed = start.elapsed();
+        best = best.min(elapsed.as_nanos() as f64 / batch as f64);
+        total += elapsed;
+        batch *= 2;
+    }
+    println!("{:<48} {:>14.1} ns/iter", name, best);
+}
+// Deterministic xorshift so every run steps through the same tiles.
+pub struct Xorshift(pub u64);
+impl Xorshift {
+    pub fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
\ No newline at end of file
@@ -0,0 +1,69 @@
+mod common;
+use std::f64::consts::PI;
+use common::{
+    bench,
+    Xorshift
+};
+use octo_sphere::{
+    octo::Octo,
+    tile::Direction,
+    voronoi::{
+        Metric,
+        Voronoi
+    }
+};
+fn circumnavigate(octo: &Octo, start_tile: usize, start_direction: Direction) -> usize {
+    let mut steps = 1;
+    let mut current = octo.step((start_tile, start_direction));
+    while current.0 != start_tile {
+        current = octo.step(current);
+        steps += 1;
+    }
+    steps
+}
+fn main() {
+    for size in [16, 128, 512].iter() {
+        bench(&format!("new/{}", size), || Octo::new(*size));
+        bench(&format!("implicit/{}", size), || Octo::implicit(*size));
+    }
+    for size in [16, 128].iter() {
+        bench(&format!("with_step_table/{}", size), || Octo::new(*size).with_step_table());
+    }
+    for size in [16, 512].iter() {
+        let octo = Octo::new(*size);
+        let implicit = Octo::implicit(*size);
+        let mut rng = Xorshift(0x2545_F491_4F6C_DD1D);
+        bench(&format!("random_step/{}", size), || {
+            octo.step((rng.next() as usize % octo.tile_count(), Direction::from_index(rng.next() as usize)))
+        });
+        bench(&format!("random_step_implicit/{}", size), || {
+            implicit.step((rng.next() as usize % implicit.tile_count(), Direction::from_index(rng.next() as usize)))
+        });
+        bench(&format!("neighbours/{}", size), || {
+            octo.neighbours(rng.next() as usize % octo.tile_count())
+        });
+    }
+    for size in [16, 512].iter() {
+        let octo = Octo::implicit(*size);
+        let mut rng = Xorshift(0x1234_5678_9ABC_DEF1);
+        bench(&format!("lat_lon/{}", size), || octo.lat_lon(rng.next() as usize % octo.tile_count()));
+        bench(&format!("tile_at/{}", size), || {
+            let lat = (rng.next() as f64 / u64::MAX as f64 - 0.5) * PI;
+            let lon = (rng.next() as f64 / u64::MAX as f64 - 0.5) * 2.0 * PI;
+            octo.tile_at(lat, lon)
+        });
+    }
+    let octo = Octo::new(64);
+    bench("neighbours_all_tiles/64", || {
+        (0..octo.tile_count()).map(|i| octo.neighbours(i)[0]).sum::<usize>()
+    });
+    for size in [16, 128].iter() {
+        let octo = Octo::new(*size);
+        bench(&format!("circumnavigate/{}", size), || {
+            (0..6).map(|d| circumnavigate(&octo, 0, Direction::from_index(d))).sum::<usize>()
+        });
+    }
+    let octo = Octo::new(256);
+    let seeds: Vec<usize> = (0..64).map(|i| i * 7919 % octo.tile_count()).collect();
+    bench("voronoi_hops/256", || Voronoi::partition(&octo, &seeds, Metric::Hops));
+}
\ No newline at end of file
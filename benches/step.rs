@@ -0,0 +1,22 @@
+mod common;
+use common::{
+    bench,
+    Xorshift
+};
+use octo_sphere::{
+    octo::Octo,
+    tile::Direction
+};
+fn main() {
+    for size in [8, 64, 512].iter() {
+        let computed = Octo::new(*size);
+        let table = Octo::new(*size).with_step_table();
+        for (label, octo) in [("computed", &computed), ("table", &table)].iter() {
+            let mut rng = Xorshift(0x9E37_79B9_7F4A_7C15);
+            bench(&format!("step/{}/{}", label, size), || {
+                let tile_id = rng.next() as usize % octo.tile_count();
+                octo.step((tile_id, Direction::from_index(rng.next() as usize)))
+            });
+        }
+    }
+}
\ No newline at end of file
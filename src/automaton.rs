@@ -0,0 +1,136 @@
+#[cfg(test)]
+#[path = "./automaton_tests.rs"]
+mod automaton_tests;
+use std::thread;
+use crate::{
+    octo::Octo,
+    tile::TileType
+};
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum Neighbourhood {
+    // The three tiles across an edge.
+    Edge,
+    // Every tile sharing a corner, twelve away from the octahedron vertices.
+    // The edge neighbours come first so rules can still tell them apart.
+    Vertex
+}
+// What a rule sees when computing the next state of one tile.
+pub struct Context<'a, S> {
+    pub tile_id: usize,
+    pub tile_type: TileType,
+    pub state: &'a S,
+    neighbours: &'a [usize],
+    states: &'a [S]
+}
+impl<'a, S> Context<'a, S> {
+    pub fn neighbours(&self) -> &'a [usize] {
+        self.neighbours
+    }
+    // The three tiles across an edge, whatever the neighbourhood.
+    pub fn edge_neighbours(&self) -> &'a [usize] {
+        &self.neighbours[..3]
+    }
+    // Tiles sharing only a corner, empty for the edge neighbourhood.
+    pub fn corner_neighbours(&self) -> &'a [usize] {
+        &self.neighbours[3..]
+    }
+    pub fn neighbour_states(&self) -> impl Iterator<Item = &'a S> + '_ {
+        self.neighbours.iter().map(move |n| &self.states[*n])
+    }
+    pub fn count<P: Fn(&S) -> bool>(&self, predicate: P) -> usize {
+        self.neighbour_states().filter(|s| predicate(s)).count()
+    }
+    pub fn edge_count<P: Fn(&S) -> bool>(&self, predicate: P) -> usize {
+        self.edge_neighbours().iter().filter(|n| predicate(&self.states[**n])).count()
+    }
+    pub fn corner_count<P: Fn(&S) -> bool>(&self, predicate: P) -> usize {
+        self.corner_neighbours().iter().filter(|n| predicate(&self.states[**n])).count()
+    }
+}
+// Double buffered automaton, every tile is updated from the previous
+// generation so the result does not depend on update order.
+#[derive(Clone,Debug,PartialEq)]
+pub struct Automaton<S> {
+    states: Vec<S>,
+    next: Vec<S>,
+    tile_types: Vec<TileType>,
+    offsets: Vec<usize>,
+    neighbours: Vec<usize>,
+    generation: usize
+}
+impl<S: Clone> Automaton<S> {
+    pub fn new(octo: &Octo, neighbourhood: Neighbourhood, initial: Vec<S>) -> Automaton<S> {
+        assert_eq!(initial.len(), octo.tile_count(), "one initial state per tile");
+        let (mut offsets, mut neighbours) = (vec![0], Vec::new());
+        for i in 0..octo.tile_count() {
+            let edge = octo.neighbours(i);
+            neighbours.extend_from_slice(&edge);
+            if neighbourhood == Neighbourhood::Vertex {
+                neighbours.extend(octo.vertex_neighbours(i).into_iter().filter(|n| !edge.contains(n)));
+            }
+            offsets.push(neighbours.len());
+        }
+        Automaton {
+            next: initial.clone(),
+            states: initial,
+            tile_types: (0..octo.tile_count()).map(|i| octo.tile_type(i)).collect(),
+            offsets,
+            neighbours,
+            generation: 0
+        }
+    }
+    pub fn states(&self) -> &[S] {
+        &self.states
+    }
+    pub fn states_mut(&mut self) -> &mut [S] {
+        &mut self.states
+    }
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+    fn context(&self, tile_id: usize) -> Context<'_, S> {
+        Context {
+            tile_id,
+            tile_type: self.tile_types[tile_id].clone(),
+            state: &self.states[tile_id],
+            neighbours: &self.neighbours[self.offsets[tile_id]..self.offsets[tile_id + 1]],
+            states: &self.states
+        }
+    }
+    pub fn step<F: Fn(&Context<S>) -> S>(&mut self, rule: F) {
+        let mut next = std::mem::take(&mut self.next);
+        for (tile_id, state) in next.iter_mut().enumerate() {
+            *state = rule(&self.context(tile_id));
+        }
+        self.next = std::mem::replace(&mut self.states, next);
+        self.generation += 1;
+    }
+    pub fn run<F: Fn(&Context<S>) -> S>(&mut self, generations: usize, rule: F) {
+        for _ in 0..generations {
+            self.step(&rule);
+        }
+    }
+}
+impl<S: Clone + Send + Sync> Automaton<S> {
+    // Same as step but splits the tiles into contiguous face major chunks
+    // across the given number of threads.
+    pub fn step_parallel<F: Fn(&Context<S>) -> S + Sync>(&mut self, rule: F, threads: usize) {
+        let mut next = std::mem::take(&mut self.next);
+        let chunk = next.len().div_ceil(threads.max(1)).max(1);
+        {
+            let this = &*self;
+            let rule = &rule;
+            thread::scope(|scope| {
+                for (c, states) in next.chunks_mut(chunk).enumerate() {
+                    scope.spawn(move || {
+                        for (offset, state) in states.iter_mut().enumerate() {
+                            *state = rule(&this.context(c * chunk + offset));
+                        }
+                    });
+                }
+            });
+        }
+        self.next = std::mem::replace(&mut self.states, next);
+        self.generation += 1;
+    }
+}
\ No newline at end of file
@@ -0,0 +1,102 @@
+#[cfg(test)]
+use crate::{
+    automaton::{
+        Automaton,
+        Context,
+        Neighbourhood
+    },
+    octo::Octo,
+    rng::Rng,
+    tile::TileType
+};
+#[cfg(test)]
+fn life(context: &Context<bool>) -> bool {
+    match (*context.state, context.count(|s| *s)) {
+        (true, n) => n == 2 || n == 3 || n == 4,
+        (false, n) => n == 4 || (n == 5 && context.tile_type == TileType::Point)
+    }
+}
+#[test]
+fn test_fire_spreads_one_edge_per_generation() -> Result <(),String> {
+    let octo = Octo::new(6);
+    let mut initial = vec![0u8; octo.tile_count()];
+    initial[40] = 1;
+    let mut fire = Automaton::new(&octo, Neighbourhood::Edge, initial);
+    let mut distance = vec![usize::MAX; octo.tile_count()];
+    let mut frontier = vec![40];
+    distance[40] = 0;
+    for d in 1..6 {
+        frontier = frontier.iter().flat_map(|t| octo.neighbours(*t).to_vec()).collect();
+        frontier.retain(|t| distance[*t] == usize::MAX);
+        for t in frontier.iter() {
+            distance[*t] = d;
+        }
+    }
+    for generation in 1..6 {
+        fire.step(|c| match *c.state {
+            0 if c.count(|s| *s == 1) > 0 => 1,
+            1 => 2,
+            s => s
+        });
+        for (t, state) in fire.states().iter().enumerate() {
+            let expected = match distance[t] {
+                d if d < generation => 2,
+                d if d == generation => 1,
+                _ => 0
+            };
+            assert_eq!(*state, expected);
+        }
+    }
+    assert_eq!(fire.generation(), 5);
+    Ok(())
+}
+#[test]
+fn test_parallel_matches_serial() -> Result <(),String> {
+    let octo = Octo::new(10);
+    let mut rng = Rng::new(17);
+    let initial: Vec<bool> = (0..octo.tile_count()).map(|_| rng.next_f64() < 0.3).collect();
+    let mut serial = Automaton::new(&octo, Neighbourhood::Vertex, initial.clone());
+    let mut parallel = Automaton::new(&octo, Neighbourhood::Vertex, initial);
+    for _ in 0..8 {
+        serial.step(life);
+        parallel.step_parallel(life, 3);
+        assert_eq!(serial.states(), parallel.states());
+    }
+    assert!(serial.states().iter().any(|s| *s));
+    Ok(())
+}
+#[test]
+fn test_rule_sees_tile_type() -> Result <(),String> {
+    let octo = Octo::implicit(4);
+    let mut automaton = Automaton::new(&octo, Neighbourhood::Edge, vec![0usize; octo.tile_count()]);
+    automaton.step(|c| match c.tile_type {
+        TileType::Point => c.neighbours().len(),
+        TileType::Flat => 0
+    });
+    for i in 0..octo.tile_count() {
+        assert_eq!(automaton.states()[i] == 3, octo.tile_type(i) == TileType::Point);
+    }
+    Ok(())
+}
+#[test]
+fn test_edge_and_corner_neighbours_kept_apart() -> Result <(),String> {
+    let octo = Octo::new(5);
+    let initial: Vec<usize> = (0..octo.tile_count()).collect();
+    let mut automaton = Automaton::new(&octo, Neighbourhood::Vertex, initial);
+    automaton.step(|c| {
+        assert_eq!(c.edge_neighbours(), &octo.neighbours(c.tile_id)[..]);
+        assert!(c.corner_neighbours().iter().all(|n| !c.edge_neighbours().contains(n)));
+        assert_eq!(c.edge_count(|_| true) + c.corner_count(|_| true), octo.vertex_neighbours(c.tile_id).len());
+        c.corner_count(|s| *s % 3 == 1) * 10 + c.edge_count(|s| *s % 3 == 1)
+    });
+    for t in 0..octo.tile_count() {
+        let marked = |n: &usize| *n % 3 == 1;
+        let edge = octo.neighbours(t).iter().filter(|n| marked(n)).count();
+        let corner = octo.vertex_neighbours(t).iter().filter(|n| marked(n) && !octo.neighbours(t).contains(n)).count();
+        assert_eq!(automaton.states()[t], corner * 10 + edge);
+    }
+    let mut edges_only = Automaton::new(&octo, Neighbourhood::Edge, vec![0usize; octo.tile_count()]);
+    edges_only.step(|c| c.corner_neighbours().len());
+    assert!(edges_only.states().iter().all(|s| *s == 0));
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,61 @@
+// Tiles grouped by hemisphere and latitude.
+// Faces 0 to 3 are northern and 4 to 7 southern, and within a face latitude
+// falls row by row away from the pole. A latitude band is found a row at a
+// time: rows whose whole extent lies inside or outside the band are taken or
+// skipped outright, and only rows straddling an edge of the band check their
+// tile centroids.
+#[cfg(test)]
+#[path = "./bands_tests.rs"]
+mod bands_tests;
+use std::ops::Range;
+use crate::octo::Octo;
+impl Octo {
+    pub fn northern_tiles(&self) -> Range<usize> {
+        0..4 * usize::pow(self.face_size(), 2)
+    }
+    pub fn southern_tiles(&self) -> Range<usize> {
+        4 * usize::pow(self.face_size(), 2)..self.tile_count()
+    }
+    pub fn row_tiles(&self, face_id: usize, row: usize) -> Range<usize> {
+        let start = face_id * usize::pow(self.face_size(), 2) + usize::pow(row, 2);
+        start..start + 2 * row + 1
+    }
+    // The last row of every face, the ring of tiles touching the equator.
+    pub fn equator_tiles(&self) -> impl Iterator<Item = usize> + '_ {
+        let n = self.face_size();
+        (0..8).flat_map(move |face_id| self.row_tiles(face_id, n - 1))
+    }
+    // Tiles whose centroid latitude lies in [south, north], in radians.
+    pub fn latitude_band(&self, south: f64, north: f64) -> impl Iterator<Item = usize> + '_ {
+        let n = self.face_size();
+        (0..8).flat_map(move |face_id| (0..n).map(move |row| (face_id, row)))
+            .flat_map(move |(face_id, row)| {
+                let (low, high) = self.row_latitudes(face_id, row);
+                let whole = south <= low && high <= north;
+                let none = high < south || north < low;
+                self.row_tiles(face_id, row).filter(move |t| match (whole, none) {
+                    (true, _) => true,
+                    (_, true) => false,
+                    _ => {
+                        let lat = self.lat_lon(*t).0;
+                        south <= lat && lat <= north
+                    }
+                })
+            })
+    }
+    // Bounds on the latitude of any point in a row of a face. Lines of equal
+    // pole weight are furthest from the pole at the face edges and closest
+    // midway between them.
+    fn row_latitudes(&self, face_id: usize, row: usize) -> (f64, f64) {
+        let n = self.face_size() as f64;
+        let (near, far) = (1.0 - row as f64 / n, 1.0 - (row + 1) as f64 / n);
+        let latitude = |pole: f64, east: f64| {
+            let rest = 1.0 - pole;
+            self.face_point(face_id, [pole, rest * (1.0 - east), rest * east])[2].clamp(-1.0, 1.0).asin()
+        };
+        let samples = [latitude(near, 0.0), latitude(near, 0.5), latitude(far, 0.0), latitude(far, 0.5)];
+        let low = samples.iter().cloned().fold(f64::INFINITY, f64::min);
+        let high = samples.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        (low - 1e-12, high + 1e-12)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,42 @@
+#[cfg(test)]
+use crate::{
+    octo::Octo,
+    projection::Projection
+};
+#[test]
+fn test_hemispheres() -> Result <(),String> {
+    let octo = Octo::new(5);
+    assert_eq!(octo.northern_tiles().len() + octo.southern_tiles().len(), octo.tile_count());
+    assert!(octo.northern_tiles().all(|t| octo.centroid(t)[2] > 0.0));
+    assert!(octo.southern_tiles().all(|t| octo.centroid(t)[2] < 0.0));
+    Ok(())
+}
+#[test]
+fn test_equator_tiles() -> Result <(),String> {
+    for size in 1..7 {
+        let octo = Octo::new(size);
+        let ring: Vec<usize> = octo.equator_tiles().collect();
+        let expected: Vec<usize> = (0..octo.tile_count()).filter(|t| octo.is_equator_tile(*t)).collect();
+        assert_eq!(ring, expected);
+        assert_eq!(ring.len(), 8 * (2 * size - 1));
+    }
+    Ok(())
+}
+#[test]
+fn test_band_matches_centroids() -> Result <(),String> {
+    let degrees = |d: f64| d.to_radians();
+    for projection in [Projection::Gnomonic, Projection::Slerp, Projection::EqualArea] {
+        let octo = Octo::new(9).with_projection(projection);
+        for (south, north) in [(-90.0, 90.0), (10.0, 35.0), (-60.0, -59.0), (-5.0, 5.0), (80.0, 90.0)] {
+            let band: Vec<usize> = octo.latitude_band(degrees(south), degrees(north)).collect();
+            let expected: Vec<usize> = (0..octo.tile_count())
+                .filter(|t| {
+                    let lat = octo.lat_lon(*t).0;
+                    degrees(south) <= lat && lat <= degrees(north)
+                })
+                .collect();
+            assert_eq!(band, expected, "{:?} band {} to {}", projection, south, north);
+        }
+    }
+    Ok(())
+}
\ No newline at end of file
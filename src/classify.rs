@@ -0,0 +1,65 @@
+// Names for the special cases of movement described in the README.
+// Moves between the pole tiles of different faces are polar corners and
+// moves through one of the four equatorial vertices are equatorial corners,
+// together covering the six singular vertices where only four tiles meet.
+// Any other move changing face is an equatorial crossing when it changes
+// hemisphere and a side crossing when it does not.
+#[cfg(test)]
+#[path = "./classify_tests.rs"]
+mod classify_tests;
+use crate::{
+    octo::Octo,
+    tile::Direction
+};
+// Ordered from least to most special, a tile takes the most special class of
+// the moves leaving it.
+#[derive(Clone,Copy,Debug,PartialEq,Eq,PartialOrd,Ord,Hash)]
+pub enum MoveClass {
+    WithinFace,
+    SideCrossing,
+    EquatorialCrossing,
+    EquatorialCorner,
+    PolarCorner
+}
+impl MoveClass {
+    // Passes through one of the six octahedron vertices.
+    pub fn is_singular(self) -> bool {
+        matches!(self, MoveClass::EquatorialCorner | MoveClass::PolarCorner)
+    }
+}
+impl Octo {
+    pub fn move_class(&self, vector: (usize, Direction)) -> MoveClass {
+        let face_base = usize::pow(self.face_size(), 2);
+        let (tile_id, direction) = vector;
+        let face_id = tile_id / face_base;
+        let next = self.step((tile_id, direction.clone())).0;
+        let next_face = next / face_base;
+        if next_face == face_id {
+            return MoveClass::WithinFace;
+        }
+        if self.is_pole_tile(tile_id) && self.is_pole_tile(next) {
+            return MoveClass::PolarCorner;
+        }
+        if !self.edge_directions(tile_id).contains(&direction) {
+            // The vertex crossed is the one missing from the neighbour across
+            // the opposite edge.
+            let n = self.face_size() as i64;
+            let across = self.vertex_keys(self.step((tile_id, -direction)).0);
+            let keys = self.vertex_keys(tile_id);
+            if let Some(key) = keys.iter().find(|k| !across.contains(k)) {
+                if key[0].abs() == n || key[1].abs() == n {
+                    return MoveClass::EquatorialCorner;
+                }
+            }
+        }
+        match (face_id > 3) == (next_face > 3) {
+            true => MoveClass::SideCrossing,
+            false => MoveClass::EquatorialCrossing
+        }
+    }
+    pub fn tile_class(&self, tile_id: usize) -> MoveClass {
+        (0..6).map(|d| self.move_class((tile_id, Direction::from_index(d))))
+            .max()
+            .unwrap_or(MoveClass::WithinFace)
+    }
+}
\ No newline at end of file
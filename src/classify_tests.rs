@@ -0,0 +1,67 @@
+#[cfg(test)]
+use crate::{
+    classify::MoveClass,
+    octo::Octo,
+    symmetry::Symmetry,
+    tile::Direction
+};
+#[test]
+fn test_move_counts() -> Result <(),String> {
+    for size in 2..8 {
+        let octo = Octo::new(size);
+        let mut counts = [0; 5];
+        for t in 0..octo.tile_count() {
+            for d in Direction::all() {
+                counts[octo.move_class((t, d)) as usize] += 1;
+            }
+        }
+        // Three ways off each pole tile and four ways through each of the
+        // four equatorial vertices.
+        assert_eq!(counts[MoveClass::PolarCorner as usize], 24, "size {}", size);
+        assert_eq!(counts[MoveClass::EquatorialCorner as usize], 16, "size {}", size);
+        assert_eq!(counts.iter().sum::<usize>(), 6 * octo.tile_count());
+    }
+    Ok(())
+}
+#[test]
+fn test_singular_moves_stay_at_vertex() -> Result <(),String> {
+    let octo = Octo::new(5);
+    for t in 0..octo.tile_count() {
+        for d in Direction::all() {
+            let class = octo.move_class((t, d.clone()));
+            if class.is_singular() {
+                assert!(octo.is_corner_tile(t));
+                assert!(octo.is_corner_tile(octo.step((t, d)).0));
+            }
+        }
+    }
+    Ok(())
+}
+#[test]
+fn test_tile_classes() -> Result <(),String> {
+    let octo = Octo::new(6);
+    for t in 0..octo.tile_count() {
+        let expected = match t % 36 {
+            0 => MoveClass::PolarCorner,
+            25 | 35 => MoveClass::EquatorialCorner,
+            i if i >= 25 => MoveClass::EquatorialCrossing,
+            _ if octo.vertex_keys(t).iter().any(|k| k[0] == 0 || k[1] == 0) => MoveClass::SideCrossing,
+            _ => MoveClass::WithinFace
+        };
+        assert_eq!(octo.tile_class(t), expected, "tile {}", t);
+    }
+    Ok(())
+}
+#[test]
+fn test_classes_follow_rotations() -> Result <(),String> {
+    let octo = Octo::new(4);
+    for s in [Symmetry::rotate_polar(1), Symmetry::flip_poles(), Symmetry::swap_hemispheres()] {
+        for t in 0..octo.tile_count() {
+            for d in Direction::all() {
+                let image = octo.apply_symmetry_vector(&s, (t, d.clone()));
+                assert_eq!(octo.move_class(image), octo.move_class((t, d)));
+            }
+        }
+    }
+    Ok(())
+}
\ No newline at end of file
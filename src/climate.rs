@@ -0,0 +1,155 @@
+#[cfg(test)]
+#[path = "./climate_tests.rs"]
+mod climate_tests;
+use crate::{
+    octo::Octo,
+    sphere::{
+        east,
+        north,
+        to_lat_lon
+    },
+    tile::Direction
+};
+#[derive(Clone,Debug,PartialEq)]
+pub struct ClimateConfig {
+    // Degrees Celsius at sea level.
+    pub equator_temperature: f64,
+    pub pole_temperature: f64,
+    // Degrees lost per unit of elevation above sea level.
+    pub lapse_rate: f64,
+    // Moisture picked up by each ocean tile per pass.
+    pub evaporation: f64,
+    // Fraction of carried moisture that falls on every step.
+    pub base_rainfall: f64,
+    // Extra fraction that falls per unit of elevation climbed.
+    pub orographic_rainfall: f64,
+    pub iterations: usize,
+    // Centimetres of yearly rainfall per unit of precipitation, used only to
+    // place tiles on the Whittaker diagram.
+    pub rainfall_scale: f64
+}
+impl Default for ClimateConfig {
+    fn default() -> Self {
+        ClimateConfig {
+            equator_temperature: 28.0,
+            pole_temperature: -25.0,
+            lapse_rate: 20.0,
+            evaporation: 1.0,
+            base_rainfall: 0.1,
+            orographic_rainfall: 1.0,
+            iterations: 64,
+            rainfall_scale: 100.0
+        }
+    }
+}
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum Biome {
+    Ocean,
+    Ice,
+    Tundra,
+    BorealForest,
+    TemperateDesert,
+    TemperateGrassland,
+    TemperateForest,
+    TemperateRainforest,
+    SubtropicalDesert,
+    Savanna,
+    TropicalSeasonalForest,
+    TropicalRainforest
+}
+impl Biome {
+    // Whittaker style lookup from mean temperature in Celsius and yearly
+    // rainfall in centimetres.
+    pub fn classify(temperature: f64, rainfall: f64, land: bool) -> Biome {
+        match (land, temperature) {
+            (false, _) => Biome::Ocean,
+            (_, t) if t < -10.0 => Biome::Ice,
+            (_, t) if t < 0.0 => Biome::Tundra,
+            (_, t) if t < 7.0 => match rainfall {
+                r if r < 25.0 => Biome::Tundra,
+                _ => Biome::BorealForest
+            },
+            (_, t) if t < 20.0 => match rainfall {
+                r if r < 25.0 => Biome::TemperateDesert,
+                r if r < 100.0 => Biome::TemperateGrassland,
+                r if r < 200.0 => Biome::TemperateForest,
+                _ => Biome::TemperateRainforest
+            },
+            _ => match rainfall {
+                r if r < 50.0 => Biome::SubtropicalDesert,
+                r if r < 150.0 => Biome::Savanna,
+                r if r < 250.0 => Biome::TropicalSeasonalForest,
+                _ => Biome::TropicalRainforest
+            }
+        }
+    }
+}
+// Three cell circulation: trade winds below 30 degrees and polar easterlies
+// above 60 blow west and toward the equator, westerlies in between blow east
+// and toward the pole.
+pub fn prevailing_wind(octo: &Octo, tile_id: usize) -> Direction {
+    let p = octo.centroid(tile_id);
+    let lat = to_lat_lon(p).0.to_degrees();
+    let (e, n) = (east(p), north(p));
+    let poleward = lat.signum();
+    let (zonal, meridional) = match lat.abs() {
+        l if l < 30.0 => (-1.0, -0.5 * poleward),
+        l if l < 60.0 => (1.0, 0.5 * poleward),
+        _ => (-1.0, -0.5 * poleward)
+    };
+    octo.direction_toward(tile_id, [
+        zonal * e[0] + meridional * n[0],
+        zonal * e[1] + meridional * n[1],
+        zonal * e[2] + meridional * n[2]
+    ])
+}
+#[derive(Clone,Debug,PartialEq)]
+pub struct Climate {
+    pub temperature: Vec<f64>,
+    pub wind: Vec<Direction>,
+    pub precipitation: Vec<f64>,
+    pub biome: Vec<Biome>
+}
+impl Climate {
+    pub fn simulate(octo: &Octo, elevation: &[f64], sea_level: f64, config: &ClimateConfig) -> Climate {
+        let tile_count = octo.tile_count();
+        let land: Vec<bool> = elevation.iter().map(|e| *e > sea_level).collect();
+        let height: Vec<f64> = elevation.iter().map(|e| (e - sea_level).max(0.0)).collect();
+        let temperature: Vec<f64> = (0..tile_count).map(|i| {
+            let lat = octo.lat_lon(i).0.abs() / std::f64::consts::FRAC_PI_2;
+            config.equator_temperature + (config.pole_temperature - config.equator_temperature) * lat
+                - config.lapse_rate * height[i]
+        }).collect();
+        let wind: Vec<Direction> = (0..tile_count).map(|i| prevailing_wind(octo, i)).collect();
+        let downwind: Vec<usize> = (0..tile_count).map(|i| octo.step((i, wind[i].clone())).0).collect();
+        let mut moisture = vec![0.0; tile_count];
+        let mut precipitation = vec![0.0; tile_count];
+        for _ in 0..config.iterations {
+            for i in (0..tile_count).filter(|i| !land[*i]) {
+                moisture[i] += config.evaporation;
+            }
+            let mut carried = vec![0.0; tile_count];
+            for i in 0..tile_count {
+                let target = downwind[i];
+                let climb = (height[target] - height[i]).max(0.0);
+                let fraction = (config.base_rainfall + config.orographic_rainfall * climb).min(1.0);
+                let rain = moisture[i] * fraction;
+                precipitation[target] += rain;
+                carried[target] += moisture[i] - rain;
+            }
+            moisture = carried;
+        }
+        for p in precipitation.iter_mut() {
+            *p /= config.iterations.max(1) as f64;
+        }
+        let biome = (0..tile_count)
+            .map(|i| Biome::classify(temperature[i], precipitation[i] * config.rainfall_scale, land[i]))
+            .collect();
+        Climate {
+            temperature,
+            wind,
+            precipitation,
+            biome
+        }
+    }
+}
\ No newline at end of file
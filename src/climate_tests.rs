@@ -0,0 +1,73 @@
+#[cfg(test)]
+use crate::{
+    climate::{
+        prevailing_wind,
+        Biome,
+        Climate,
+        ClimateConfig
+    },
+    octo::Octo,
+    sphere::{
+        dot,
+        east
+    },
+    terrain::{
+        Terrain,
+        TerrainConfig
+    }
+};
+#[test]
+fn test_wind_bands() -> Result <(),String> {
+    let octo = Octo::new(12);
+    for (lat, eastward) in [(0.2, false), (-0.3, false), (0.8, true), (-0.8, true), (1.3, false)].iter() {
+        for lon in [-2.5, -0.4, 0.7, 2.9].iter() {
+            let tile = octo.tile_at(*lat, *lon);
+            let wind = octo.tangent(tile, &prevailing_wind(&octo, tile));
+            assert_eq!(dot(wind, east(octo.centroid(tile))) > 0.0, *eastward, "lat {} lon {}", lat, lon);
+        }
+    }
+    Ok(())
+}
+#[test]
+fn test_climate_gradients() -> Result <(),String> {
+    let octo = Octo::new(16);
+    let terrain = Terrain::generate(&octo, &TerrainConfig {
+        seed: 4,
+        ..TerrainConfig::default()
+    });
+    let climate = Climate::simulate(&octo, &terrain.elevation, terrain.sea_level, &ClimateConfig::default());
+    let equator = octo.tile_at(0.01, 0.5);
+    let pole = octo.tile_at(1.5, 0.5);
+    assert!(climate.precipitation.iter().all(|p| *p >= 0.0));
+    assert!((0..octo.tile_count()).any(|i| terrain.is_land(i) && climate.precipitation[i] > 0.0));
+    for i in 0..octo.tile_count() {
+        assert_eq!(climate.biome[i] == Biome::Ocean, !terrain.is_land(i));
+    }
+    let flat = vec![0.5; octo.tile_count()];
+    let lowland = Climate::simulate(&octo, &flat, 0.0, &ClimateConfig::default());
+    assert!(lowland.temperature[equator] > lowland.temperature[pole] + 30.0);
+    // Raising a tile cools it in proportion to the height gained.
+    for config in [ClimateConfig::default(), ClimateConfig { lapse_rate: 6.5, ..ClimateConfig::default() }].iter() {
+        let lowland = Climate::simulate(&octo, &flat, 0.0, config);
+        for tile in [equator, pole, octo.tile_at(-0.7, 2.0)].iter() {
+            for rise in [0.25, 1.0, 2.5].iter() {
+                let mut mountain = flat.clone();
+                mountain[*tile] += rise;
+                let highland = Climate::simulate(&octo, &mountain, 0.0, config);
+                let drop = lowland.temperature[*tile] - highland.temperature[*tile];
+                assert!((drop - config.lapse_rate * rise).abs() < 1e-9, "tile {} rise {} drop {}", tile, rise, drop);
+            }
+        }
+    }
+    Ok(())
+}
+#[test]
+fn test_whittaker_classification() -> Result <(),String> {
+    assert_eq!(Biome::classify(25.0, 300.0, true), Biome::TropicalRainforest);
+    assert_eq!(Biome::classify(25.0, 10.0, true), Biome::SubtropicalDesert);
+    assert_eq!(Biome::classify(12.0, 150.0, true), Biome::TemperateForest);
+    assert_eq!(Biome::classify(3.0, 80.0, true), Biome::BorealForest);
+    assert_eq!(Biome::classify(-20.0, 80.0, true), Biome::Ice);
+    assert_eq!(Biome::classify(25.0, 300.0, false), Biome::Ocean);
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,128 @@
+#[cfg(test)]
+#[path = "./components_tests.rs"]
+mod components_tests;
+use crate::{
+    automaton::Neighbourhood,
+    octo::Octo
+};
+// Refills the buffer with the tile's neighbours, so a search allocates once
+// rather than once per tile.
+fn adjacent(octo: &Octo, tile_id: usize, neighbourhood: Neighbourhood, buffer: &mut Vec<usize>) {
+    match neighbourhood {
+        Neighbourhood::Edge => {
+            buffer.clear();
+            buffer.extend_from_slice(&octo.neighbours(tile_id));
+        },
+        Neighbourhood::Vertex => octo.vertex_neighbours_into(tile_id, buffer)
+    }
+}
+// Every tile connected to seed through tiles accepted by predicate, in the
+// order they were reached. Empty when the seed itself is rejected.
+pub fn flood_fill<P: Fn(usize) -> bool>(octo: &Octo, seed: usize, neighbourhood: Neighbourhood, predicate: P) -> Vec<usize> {
+    flood_fill_with(octo, seed, neighbourhood, &mut vec![false; octo.tile_count()], predicate)
+}
+// As flood_fill but marking visits in a caller's buffer of tile_count falses,
+// which is handed back all false so repeated fills skip the allocation.
+pub fn flood_fill_with<P: Fn(usize) -> bool>(octo: &Octo, seed: usize, neighbourhood: Neighbourhood, seen: &mut [bool], predicate: P) -> Vec<usize> {
+    if !predicate(seed) {
+        return Vec::new();
+    }
+    seen[seed] = true;
+    let mut filled = vec![seed];
+    let mut buffer = Vec::with_capacity(12);
+    let mut next = 0;
+    while next < filled.len() {
+        adjacent(octo, filled[next], neighbourhood, &mut buffer);
+        for &n in buffer.iter() {
+            if !seen[n] && predicate(n) {
+                seen[n] = true;
+                filled.push(n);
+            }
+        }
+        next += 1;
+    }
+    for t in filled.iter() {
+        seen[*t] = false;
+    }
+    filled
+}
+#[derive(Clone,Debug,PartialEq)]
+pub struct Component {
+    pub tiles: Vec<usize>,
+    // Member tiles with at least one neighbour outside the component.
+    pub border: Vec<usize>
+}
+impl Component {
+    pub fn size(&self) -> usize {
+        self.tiles.len()
+    }
+}
+#[derive(Clone,Debug,PartialEq)]
+pub struct Components {
+    // Component index of each tile, None for tiles that were not labelled.
+    pub label: Vec<Option<usize>>,
+    pub components: Vec<Component>
+}
+impl Components {
+    // Joins neighbouring tiles holding equal values, so a boolean layer gives
+    // both its true and its false regions.
+    pub fn label<T: PartialEq>(octo: &Octo, layer: &[T], neighbourhood: Neighbourhood) -> Components {
+        Components::label_by(octo, neighbourhood, |_| true, |a, b| layer[a] == layer[b])
+    }
+    // Labels only the tiles accepted by predicate, e.g. land for continents.
+    pub fn label_where<P: Fn(usize) -> bool>(octo: &Octo, neighbourhood: Neighbourhood, predicate: P) -> Components {
+        Components::label_by(octo, neighbourhood, predicate, |_, _| true)
+    }
+    fn label_by<P: Fn(usize) -> bool, S: Fn(usize, usize) -> bool>(octo: &Octo, neighbourhood: Neighbourhood, include: P, same: S) -> Components {
+        let mut label = vec![None; octo.tile_count()];
+        let mut components = Vec::new();
+        let mut buffer = Vec::with_capacity(12);
+        for seed in 0..octo.tile_count() {
+            if label[seed].is_some() || !include(seed) {
+                continue;
+            }
+            // Breadth first with the labels themselves as the visited set.
+            let c = components.len();
+            label[seed] = Some(c);
+            let mut tiles = vec![seed];
+            let mut next = 0;
+            while next < tiles.len() {
+                adjacent(octo, tiles[next], neighbourhood, &mut buffer);
+                for &n in buffer.iter() {
+                    if label[n].is_none() && include(n) && same(seed, n) {
+                        label[n] = Some(c);
+                        tiles.push(n);
+                    }
+                }
+                next += 1;
+            }
+            let border = tiles.iter()
+                .cloned()
+                .filter(|t| {
+                    adjacent(octo, *t, neighbourhood, &mut buffer);
+                    buffer.iter().any(|n| label[*n] != Some(c))
+                })
+                .collect();
+            components.push(Component {
+                tiles,
+                border
+            });
+        }
+        Components {
+            label,
+            components
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+    pub fn sizes(&self) -> Vec<usize> {
+        self.components.iter().map(|c| c.size()).collect()
+    }
+    pub fn largest(&self) -> Option<&Component> {
+        self.components.iter().max_by_key(|c| c.size())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,65 @@
+#[cfg(test)]
+use crate::{
+    automaton::Neighbourhood,
+    components::{
+        flood_fill,
+        flood_fill_with,
+        Components
+    },
+    octo::Octo,
+    terrain::{
+        Terrain,
+        TerrainConfig
+    }
+};
+#[test]
+fn test_flood_fill_crosses_faces() -> Result <(),String> {
+    let octo = Octo::new(5);
+    let cap = flood_fill(&octo, 0, Neighbourhood::Edge, |t| octo.lat_lon(t).0 > 0.8);
+    assert!(cap.iter().any(|t| t / 25 == 1) && cap.iter().any(|t| t / 25 == 3));
+    assert_eq!(cap.len(), (0..octo.tile_count()).filter(|t| octo.lat_lon(*t).0 > 0.8).count());
+    assert!(flood_fill(&octo, 0, Neighbourhood::Edge, |t| t != 0).is_empty());
+    let everything = flood_fill(&octo, 77, Neighbourhood::Vertex, |_| true);
+    assert_eq!(everything.len(), octo.tile_count());
+    let mut seen = vec![false; octo.tile_count()];
+    assert_eq!(flood_fill_with(&octo, 0, Neighbourhood::Edge, &mut seen, |t| octo.lat_lon(t).0 > 0.8), cap);
+    assert!(seen.iter().all(|s| !s));
+    assert_eq!(flood_fill_with(&octo, 0, Neighbourhood::Edge, &mut seen, |t| octo.lat_lon(t).0 > 0.8), cap);
+    Ok(())
+}
+#[test]
+fn test_label_boolean_layer() -> Result <(),String> {
+    let octo = Octo::new(4);
+    let band: Vec<bool> = (0..octo.tile_count()).map(|t| octo.lat_lon(t).0.abs() < 0.3).collect();
+    let components = Components::label(&octo, &band, Neighbourhood::Edge);
+    assert_eq!(components.len(), 3);
+    assert_eq!(components.sizes().iter().sum::<usize>(), octo.tile_count());
+    for (c, component) in components.components.iter().enumerate() {
+        for t in component.tiles.iter() {
+            assert_eq!(components.label[*t], Some(c));
+        }
+        for t in component.border.iter() {
+            assert!(octo.neighbours(*t).iter().any(|n| components.label[*n] != Some(c)));
+        }
+    }
+    let hemisphere = Components::label(&octo, &(0..octo.tile_count()).map(|t| t < 64).collect::<Vec<bool>>(), Neighbourhood::Vertex);
+    assert_eq!(hemisphere.sizes(), vec![64, 64]);
+    Ok(())
+}
+#[test]
+fn test_label_continents() -> Result <(),String> {
+    let octo = Octo::new(20);
+    let terrain = Terrain::generate(&octo, &TerrainConfig {
+        seed: 2,
+        ..TerrainConfig::default()
+    });
+    let continents = Components::label_where(&octo, Neighbourhood::Edge, |t| terrain.is_land(t));
+    let land = (0..octo.tile_count()).filter(|t| terrain.is_land(*t)).count();
+    assert_eq!(continents.sizes().iter().sum::<usize>(), land);
+    for t in 0..octo.tile_count() {
+        assert_eq!(continents.label[t].is_some(), terrain.is_land(t));
+    }
+    let largest = continents.largest().ok_or("no land")?;
+    assert_eq!(flood_fill(&octo, largest.tiles[0], Neighbourhood::Edge, |t| terrain.is_land(t)).len(), largest.size());
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,88 @@
+// Exact integer coordinates of tiles.
+// Within a face, row h holds positions 0 to 2h from west to east, even
+// positions being Point tiles. Triangular coordinates (a, b, c) count lattice
+// rows toward the pole, west and east corners of the face: a tile's corner
+// lattice points are (a, b, c) plus one unit on each axis in turn for a Point
+// tile, so a + b + c is face_size - 1, and minus one unit in turn for a Flat
+// tile, so a + b + c is face_size - 2.
+#[cfg(test)]
+#[path = "./coords_tests.rs"]
+mod coords_tests;
+use crate::octo::{
+    isqrt,
+    Octo
+};
+#[derive(Clone,Copy,Debug,PartialEq,Eq,Hash)]
+pub struct FacePosition {
+    pub face: usize,
+    pub row: usize,
+    pub position: usize
+}
+#[derive(Clone,Copy,Debug,PartialEq,Eq,Hash)]
+pub struct TriCoord {
+    pub face: usize,
+    pub a: usize,
+    pub b: usize,
+    pub c: usize
+}
+impl Octo {
+    pub fn face_position(&self, tile_id: usize) -> FacePosition {
+        let face_base = usize::pow(self.face_size(), 2);
+        let index_id = tile_id % face_base;
+        let row = isqrt(index_id);
+        FacePosition {
+            face: tile_id / face_base,
+            row,
+            position: index_id - usize::pow(row, 2)
+        }
+    }
+    pub fn tile_at_position(&self, position: FacePosition) -> Option<usize> {
+        match position.face < 8 && position.row < self.face_size() && position.position <= 2 * position.row {
+            true => Some(position.face * usize::pow(self.face_size(), 2) + usize::pow(position.row, 2) + position.position),
+            false => None
+        }
+    }
+    pub fn tri_coord(&self, tile_id: usize) -> TriCoord {
+        let FacePosition { face, row, position } = self.face_position(tile_id);
+        let k = position / 2;
+        TriCoord {
+            face,
+            a: self.face_size() - 1 - row,
+            b: row - k - position % 2,
+            c: k
+        }
+    }
+    pub fn tile_at_tri(&self, coord: TriCoord) -> Option<usize> {
+        let sum = coord.a + coord.b + coord.c;
+        let n = self.face_size();
+        let flat = match sum + 2 {
+            s if s == n + 1 => 0,
+            s if s == n => 1,
+            _ => return None
+        };
+        self.tile_at_position(FacePosition {
+            face: coord.face,
+            row: n - 1 - coord.a,
+            position: 2 * coord.c + flat
+        })
+    }
+    // Shares an edge with a tile of another face.
+    pub fn is_edge_tile(&self, tile_id: usize) -> bool {
+        let FacePosition { row, position, .. } = self.face_position(tile_id);
+        position % 2 == 0 && (position == 0 || position == 2 * row || row == self.face_size() - 1)
+    }
+    // Touches one of the six octahedron vertices.
+    pub fn is_corner_tile(&self, tile_id: usize) -> bool {
+        let FacePosition { row, position, .. } = self.face_position(tile_id);
+        row == 0 || (row == self.face_size() - 1 && (position == 0 || position == 2 * row))
+    }
+    // Touches the north or south pole.
+    pub fn is_pole_tile(&self, tile_id: usize) -> bool {
+        self.face_position(tile_id).row == 0
+    }
+    // In the last row of its face, so touching the equator along an edge for
+    // Point tiles or at a vertex for Flat tiles.
+    pub fn is_equator_tile(&self, tile_id: usize) -> bool {
+        self.face_position(tile_id).row == self.face_size() - 1
+    }
+}
\ No newline at end of file
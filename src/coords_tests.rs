@@ -0,0 +1,65 @@
+#[cfg(test)]
+use crate::{
+    coords::{
+        FacePosition,
+        TriCoord
+    },
+    octo::Octo,
+    tile::TileType
+};
+#[test]
+fn test_round_trips() -> Result <(),String> {
+    for size in 1..9 {
+        let octo = Octo::implicit(size);
+        for t in 0..octo.tile_count() {
+            let position = octo.face_position(t);
+            assert_eq!(octo.tile_at_position(position), Some(t));
+            let coord = octo.tri_coord(t);
+            assert_eq!(octo.tile_at_tri(coord), Some(t));
+            let sum = coord.a + coord.b + coord.c;
+            match octo.tile_type(t) {
+                TileType::Point => assert_eq!(sum, size - 1),
+                TileType::Flat => assert_eq!(sum + 2, size)
+            }
+        }
+    }
+    Ok(())
+}
+#[test]
+fn test_exact_on_large_faces() -> Result <(),String> {
+    let octo = Octo::implicit(1 << 26);
+    let n = octo.face_size();
+    let last = octo.tile_count() - 1;
+    assert_eq!(octo.face_position(last), FacePosition { face: 7, row: n - 1, position: 2 * n - 2 });
+    assert_eq!(octo.tri_coord(last), TriCoord { face: 7, a: 0, b: 0, c: n - 1 });
+    let before_row = usize::pow(n - 1, 2) - 1;
+    assert_eq!(octo.face_position(before_row), FacePosition { face: 0, row: n - 2, position: 2 * n - 4 });
+    Ok(())
+}
+#[test]
+fn test_invalid_coordinates() -> Result <(),String> {
+    let octo = Octo::new(4);
+    assert_eq!(octo.tile_at_position(FacePosition { face: 0, row: 2, position: 5 }), None);
+    assert_eq!(octo.tile_at_position(FacePosition { face: 8, row: 0, position: 0 }), None);
+    assert_eq!(octo.tile_at_position(FacePosition { face: 0, row: 4, position: 0 }), None);
+    assert_eq!(octo.tile_at_tri(TriCoord { face: 1, a: 2, b: 2, c: 0 }), None);
+    assert_eq!(octo.tile_at_tri(TriCoord { face: 1, a: 1, b: 1, c: 0 }), Some(21));
+    assert_eq!(octo.tile_at_tri(TriCoord { face: 1, a: 3, b: 0, c: 0 }), Some(16));
+    Ok(())
+}
+#[test]
+fn test_predicates_match_adjacency() -> Result <(),String> {
+    for size in 1..7 {
+        let octo = Octo::new(size);
+        let face_base = usize::pow(size, 2);
+        for t in 0..octo.tile_count() {
+            let crosses = octo.neighbours(t).iter().any(|n| n / face_base != t / face_base);
+            assert_eq!(octo.is_edge_tile(t), crosses, "tile {} of size {}", t, size);
+            let corner = octo.vertex_neighbours(t).len() < 12;
+            assert_eq!(octo.is_corner_tile(t), corner, "tile {} of size {}", t, size);
+            assert_eq!(octo.is_pole_tile(t), octo.vertex_keys(t).iter().any(|k| k[2].unsigned_abs() as usize == size));
+            assert_eq!(octo.is_equator_tile(t), octo.vertex_keys(t).iter().any(|k| k[2] == 0));
+        }
+    }
+    Ok(())
+}
\ No newline at end of file
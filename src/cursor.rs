@@ -0,0 +1,81 @@
+// A unit standing on a tile and facing one of its six directions.
+// Raw steps report arrival directions in the destination face's own frame,
+// which flips between hemispheres and twists around the octahedron corners.
+// A cursor instead carries its heading across by looking back: after moving
+// it finds the direction that would return it to the tile it left, faces
+// the opposite way, and then reapplies whatever turn it was moving at. Moves
+// are therefore reversible and turns behave the same on every face.
+#[cfg(test)]
+#[path = "./cursor_tests.rs"]
+mod cursor_tests;
+use crate::{
+    octo::Octo,
+    sphere::Vec3,
+    tile::{
+        Direction,
+        Turn
+    }
+};
+#[derive(Clone,Debug,PartialEq)]
+pub struct Cursor {
+    tile_id: usize,
+    heading: Direction
+}
+impl Cursor {
+    pub fn new(tile_id: usize, heading: Direction) -> Cursor {
+        Cursor {
+            tile_id,
+            heading
+        }
+    }
+    pub fn tile_id(&self) -> usize {
+        self.tile_id
+    }
+    pub fn heading(&self) -> &Direction {
+        &self.heading
+    }
+    // The tile that moving forward would reach.
+    pub fn ahead(&self, octo: &Octo) -> usize {
+        octo.step((self.tile_id, self.heading.clone())).0
+    }
+    // Unit tangent of the heading at the current tile.
+    pub fn tangent(&self, octo: &Octo) -> Vec3 {
+        octo.tangent(self.tile_id, &self.heading)
+    }
+    pub fn turn(&mut self, turn: Turn) {
+        self.heading = self.heading.turn(turn);
+    }
+    pub fn turn_left(&mut self) {
+        self.turn(Turn::Left);
+    }
+    pub fn turn_right(&mut self) {
+        self.turn(Turn::Right);
+    }
+    // Moves one tile toward the given relative direction while keeping the
+    // same facing, returning the new tile.
+    pub fn shift(&mut self, octo: &Octo, turn: Turn) -> usize {
+        let moving = self.heading.turn(turn);
+        let next = octo.step((self.tile_id, moving.clone())).0;
+        let back = (0..6).map(Direction::from_index)
+            .find(|d| octo.step((next, d.clone())).0 == self.tile_id)
+            .expect("every step can be retraced");
+        let arrived = -back;
+        self.heading = arrived.turn(moving.relative(&self.heading));
+        self.tile_id = next;
+        next
+    }
+    pub fn forward(&mut self, octo: &Octo) -> usize {
+        self.shift(octo, Turn::Ahead)
+    }
+    pub fn backward(&mut self, octo: &Octo) -> usize {
+        self.shift(octo, Turn::Back)
+    }
+    // Hexagonal neighbours have no exact right angle, strafing slips sideways
+    // 60 degrees off the heading.
+    pub fn strafe_left(&mut self, octo: &Octo) -> usize {
+        self.shift(octo, Turn::Left)
+    }
+    pub fn strafe_right(&mut self, octo: &Octo) -> usize {
+        self.shift(octo, Turn::Right)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,71 @@
+#[cfg(test)]
+use crate::{
+    cursor::Cursor,
+    octo::Octo,
+    sphere::dot,
+    tile::{
+        Direction,
+        Turn
+    }
+};
+#[test]
+fn test_moves_reverse() -> Result <(),String> {
+    for size in 1..7 {
+        let octo = Octo::new(size);
+        for t in 0..octo.tile_count() {
+            for heading in Direction::all() {
+                for turn in (0..6).map(Turn::from_sixths) {
+                    let start = Cursor::new(t, heading.clone());
+                    let mut cursor = start.clone();
+                    cursor.shift(&octo, turn);
+                    cursor.shift(&octo, turn.opposite());
+                    assert_eq!(cursor, start, "size {} shifted {:?} and back", size, turn);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+#[test]
+fn test_turns_are_local() -> Result <(),String> {
+    let octo = Octo::new(4);
+    let mut cursor = Cursor::new(70, Direction::NegY);
+    cursor.turn_left();
+    assert_eq!(cursor.heading(), &Direction::NegX);
+    cursor.turn_right();
+    cursor.turn_right();
+    assert_eq!(cursor.heading(), &Direction::NegZ);
+    assert_eq!(cursor.ahead(&octo), octo.step((70, Direction::NegZ)).0);
+    let ahead = cursor.ahead(&octo);
+    assert_eq!(cursor.forward(&octo), ahead);
+    assert_eq!(cursor.tile_id(), ahead);
+    Ok(())
+}
+#[test]
+fn test_forward_keeps_course() -> Result <(),String> {
+    let octo = Octo::new(8);
+    for t in 0..octo.tile_count() {
+        for heading in Direction::all() {
+            let mut cursor = Cursor::new(t, heading);
+            let before = cursor.tangent(&octo);
+            let from = octo.centroid(t);
+            cursor.forward(&octo);
+            let after = cursor.tangent(&octo);
+            let to = octo.centroid(cursor.tile_id());
+            let travel = [to[0] - from[0], to[1] - from[1], to[2] - from[2]];
+            assert!(dot(before, travel) > 0.0 && dot(after, travel) > 0.0, "tile {} heading {:?} turned around", t, cursor.heading());
+        }
+    }
+    Ok(())
+}
+#[test]
+fn test_strafe() -> Result <(),String> {
+    let octo = Octo::new(5);
+    let mut cursor = Cursor::new(12, Direction::PosX);
+    let before = cursor.tangent(&octo);
+    assert_eq!(cursor.strafe_left(&octo), octo.step((12, Direction::NegZ)).0);
+    assert!(dot(before, cursor.tangent(&octo)) > 0.5);
+    let side = cursor.tile_id();
+    assert_eq!(cursor.strafe_right(&octo), octo.step((side, cursor.heading().rotate_cw())).0);
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,118 @@
+#[cfg(test)]
+#[path = "./diffusion_tests.rs"]
+mod diffusion_tests;
+use crate::{
+    octo::Octo,
+    sphere::{
+        arc,
+        dot
+    }
+};
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum Weighting {
+    // Every edge neighbour counts the same and every tile has unit mass.
+    Uniform,
+    // Flux across an edge scales with its length over the distance between
+    // the two centroids, and each tile is weighted by its spherical area.
+    Geometric
+}
+// Discrete Laplacian over edge neighbours. The weights are symmetric, so the
+// mass weighted sum of a field is conserved by every time step.
+#[derive(Clone,Debug,PartialEq)]
+pub struct Laplacian {
+    neighbours: Vec<[usize; 3]>,
+    weights: Vec<[f64; 3]>,
+    mass: Vec<f64>
+}
+impl Laplacian {
+    pub fn new(octo: &Octo, weighting: Weighting) -> Laplacian {
+        let neighbours: Vec<[usize; 3]> = (0..octo.tile_count()).map(|i| octo.neighbours(i)).collect();
+        let (weights, mass) = match weighting {
+            Weighting::Uniform => (vec![[1.0; 3]; octo.tile_count()], vec![1.0; octo.tile_count()]),
+            Weighting::Geometric => {
+                let weights = neighbours.iter().enumerate().map(|(i, ns)| {
+                    let vertices = octo.vertices(i);
+                    let mut w = [0.0; 3];
+                    for (k, n) in ns.iter().enumerate() {
+                        let shared: Vec<_> = octo.vertices(*n).iter()
+                            .cloned()
+                            .filter(|v| vertices.iter().any(|u| dot(*u, *v) > 1.0 - 1e-12))
+                            .collect();
+                        w[k] = arc(shared[0], shared[1]) / arc(octo.centroid(i), octo.centroid(*n));
+                    }
+                    w
+                }).collect();
+                (weights, (0..octo.tile_count()).map(|i| octo.tile_area(i)).collect())
+            }
+        };
+        Laplacian {
+            neighbours,
+            weights,
+            mass
+        }
+    }
+    pub fn mass(&self) -> &[f64] {
+        &self.mass
+    }
+    pub fn total(&self, field: &[f64]) -> f64 {
+        field.iter().zip(self.mass.iter()).map(|(f, m)| f * m).sum()
+    }
+    // Sum of weighted differences to the neighbours, before dividing by mass.
+    fn flux(&self, field: &[f64], i: usize) -> f64 {
+        (0..3).map(|k| self.weights[i][k] * (field[self.neighbours[i][k]] - field[i])).sum()
+    }
+    pub fn apply(&self, field: &[f64], out: &mut [f64]) {
+        for (i, o) in out.iter_mut().enumerate() {
+            *o = self.flux(field, i) / self.mass[i];
+        }
+    }
+    // Largest time step for which explicit_step stays stable.
+    pub fn max_stable_dt(&self, diffusivity: f64) -> f64 {
+        (0..self.mass.len())
+            .map(|i| self.mass[i] / (diffusivity * self.weights[i].iter().sum::<f64>()))
+            .fold(f64::INFINITY, f64::min)
+    }
+    // Forward Euler, only stable for dt up to max_stable_dt.
+    pub fn explicit_step(&self, field: &mut [f64], diffusivity: f64, dt: f64) {
+        let mut rate = vec![0.0; field.len()];
+        self.apply(field, &mut rate);
+        for (f, r) in field.iter_mut().zip(rate.iter()) {
+            *f += diffusivity * dt * r;
+        }
+    }
+    // Backward Euler, stable for any dt. Solves (M + dt k K) u' = M u by
+    // conjugate gradients and returns the iterations used.
+    pub fn implicit_step(&self, field: &mut [f64], diffusivity: f64, dt: f64, tolerance: f64, max_iterations: usize) -> usize {
+        let scale = diffusivity * dt;
+        let operator = |x: &[f64], out: &mut [f64]| {
+            for (i, o) in out.iter_mut().enumerate() {
+                *o = self.mass[i] * x[i] - scale * self.flux(x, i);
+            }
+        };
+        let b: Vec<f64> = field.iter().zip(self.mass.iter()).map(|(f, m)| f * m).collect();
+        let mut ax = vec![0.0; field.len()];
+        operator(field, &mut ax);
+        let mut r: Vec<f64> = b.iter().zip(ax.iter()).map(|(b, a)| b - a).collect();
+        let mut p = r.clone();
+        let mut rr: f64 = r.iter().map(|x| x * x).sum();
+        let limit = tolerance * tolerance * b.iter().map(|x| x * x).sum::<f64>().max(f64::MIN_POSITIVE);
+        let mut ap = vec![0.0; field.len()];
+        for iteration in 0..max_iterations {
+            if rr <= limit {
+                return iteration;
+            }
+            operator(&p, &mut ap);
+            let alpha = rr / p.iter().zip(ap.iter()).map(|(p, a)| p * a).sum::<f64>();
+            for i in 0..field.len() {
+                field[i] += alpha * p[i];
+                r[i] -= alpha * ap[i];
+            }
+            let next: f64 = r.iter().map(|x| x * x).sum();
+            for i in 0..field.len() {
+                p[i] = r[i] + next / rr * p[i];
+            }
+            rr = next;
+        }
+        max_iterations
+    }
+}
\ No newline at end of file
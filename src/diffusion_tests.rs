@@ -0,0 +1,62 @@
+#[cfg(test)]
+use crate::{
+    diffusion::{
+        Laplacian,
+        Weighting
+    },
+    octo::Octo
+};
+#[test]
+fn test_diffusion_conserves_and_smooths() -> Result <(),String> {
+    let octo = Octo::new(8);
+    for weighting in [Weighting::Uniform, Weighting::Geometric].iter() {
+        let laplacian = Laplacian::new(&octo, *weighting);
+        let mut explicit = vec![0.0; octo.tile_count()];
+        explicit[0] = 1.0 / laplacian.mass()[0];
+        let mut implicit = explicit.clone();
+        let total = laplacian.total(&explicit);
+        let dt = 0.5 * laplacian.max_stable_dt(1.0);
+        for _ in 0..50 {
+            laplacian.explicit_step(&mut explicit, 1.0, dt);
+            laplacian.implicit_step(&mut implicit, 1.0, dt, 1e-12, 500);
+        }
+        assert!((laplacian.total(&explicit) - total).abs() < 1e-9);
+        assert!((laplacian.total(&implicit) - total).abs() < 1e-9);
+        assert!(explicit.iter().all(|f| *f >= 0.0 && *f < 1.0 / laplacian.mass()[0]));
+        let difference = explicit.iter().zip(implicit.iter()).map(|(a, b)| (a - b).abs()).fold(0.0, f64::max);
+        assert!(difference < 0.1 * explicit[0]);
+    }
+    Ok(())
+}
+#[test]
+fn test_implicit_large_steps_reach_equilibrium() -> Result <(),String> {
+    let octo = Octo::new(6);
+    let laplacian = Laplacian::new(&octo, Weighting::Geometric);
+    let mut field: Vec<f64> = (0..octo.tile_count()).map(|i| (i % 7) as f64).collect();
+    let mean = laplacian.total(&field) / laplacian.mass().iter().sum::<f64>();
+    for _ in 0..5 {
+        assert!(laplacian.implicit_step(&mut field, 1.0, 1000.0 * laplacian.max_stable_dt(1.0), 1e-12, 1000) < 1000);
+    }
+    assert!(field.iter().all(|f| (f - mean).abs() < 1e-3));
+    let mut uniform = vec![2.5; octo.tile_count()];
+    laplacian.explicit_step(&mut uniform, 3.0, 0.01);
+    assert!(uniform.iter().all(|f| (f - 2.5).abs() < 1e-12));
+    Ok(())
+}
+#[test]
+fn test_geometric_diffusion_is_seamless() -> Result <(),String> {
+    let octo = Octo::new(9);
+    let laplacian = Laplacian::new(&octo, Weighting::Geometric);
+    let north = octo.tile_at(std::f64::consts::FRAC_PI_2, 0.0);
+    let mut field: Vec<f64> = (0..octo.tile_count()).map(|i| match octo.lat_lon(i).0 > 1.2 {
+        true => 1.0,
+        false => 0.0
+    }).collect();
+    for _ in 0..20 {
+        laplacian.implicit_step(&mut field, 1.0, 0.001, 1e-12, 500);
+    }
+    let ring: Vec<f64> = (0..4).map(|q| field[octo.tile_at(0.9, 0.3 + q as f64 * std::f64::consts::FRAC_PI_2)]).collect();
+    assert!(ring.iter().all(|f| (f - ring[0]).abs() < 1e-9));
+    assert!(field[north] > ring[0]);
+    Ok(())
+}
\ No newline at end of file
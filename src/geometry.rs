@@ -0,0 +1,60 @@
+#[cfg(test)]
+#[path = "./geometry_tests.rs"]
+mod geometry_tests;
+use crate::{
+    octo::Octo,
+    projection::Projection
+};
+#[derive(Clone,Debug,PartialEq)]
+pub struct Spread {
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub std_dev: f64
+}
+impl Spread {
+    pub fn of(values: impl IntoIterator<Item = f64>) -> Spread {
+        let values: Vec<f64> = values.into_iter().collect();
+        let count = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / count;
+        Spread {
+            min: values.iter().cloned().fold(f64::INFINITY, f64::min),
+            max: values.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+            mean,
+            std_dev: (values.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>() / count).sqrt()
+        }
+    }
+    pub fn ratio(&self) -> f64 {
+        self.max / self.min
+    }
+}
+// Tile shape statistics over a whole octahedron, areas in steradians and
+// lengths and angles in radians.
+#[derive(Clone,Debug,PartialEq)]
+pub struct GeometryReport {
+    pub face_size: usize,
+    pub projection: Projection,
+    pub area: Spread,
+    pub edge_length: Spread,
+    pub interior_angle: Spread
+}
+impl GeometryReport {
+    pub fn measure(octo: &Octo) -> GeometryReport {
+        let tiles = 0..octo.tile_count();
+        GeometryReport {
+            face_size: octo.face_size(),
+            projection: octo.projection(),
+            area: Spread::of(tiles.clone().map(|t| octo.tile_area(t))),
+            edge_length: Spread::of(tiles.clone().flat_map(|t| octo.edge_lengths(t))),
+            interior_angle: Spread::of(tiles.flat_map(|t| octo.interior_angles(t)))
+        }
+    }
+    // Largest tile area over the smallest.
+    pub fn area_ratio(&self) -> f64 {
+        self.area.ratio()
+    }
+    // Standard deviation of tile area relative to the mean.
+    pub fn area_variation(&self) -> f64 {
+        self.area.std_dev / self.area.mean
+    }
+}
\ No newline at end of file
@@ -0,0 +1,39 @@
+#[cfg(test)]
+use crate::{
+    geometry::{
+        GeometryReport,
+        Spread
+    },
+    octo::Octo,
+    projection::Projection
+};
+#[test]
+fn test_angle_excess_is_area() -> Result <(),String> {
+    let octo = Octo::new(5);
+    for t in 0..octo.tile_count() {
+        let [a, b, c] = octo.interior_angles(t);
+        let excess = a + b + c - std::f64::consts::PI;
+        assert!((excess - octo.tile_area(t)).abs() < 1e-9, "tile {} excess {} area {}", t, excess, octo.tile_area(t));
+    }
+    Ok(())
+}
+#[test]
+fn test_single_tile_faces() -> Result <(),String> {
+    let report = GeometryReport::measure(&Octo::new(1));
+    let right = std::f64::consts::FRAC_PI_2;
+    for spread in [&report.edge_length, &report.interior_angle] {
+        assert!((spread.min - right).abs() < 1e-9 && (spread.max - right).abs() < 1e-9, "{:?}", spread);
+    }
+    assert!(report.area.std_dev < 1e-9 && (report.area_ratio() - 1.0).abs() < 1e-9, "{:?}", report.area);
+    Ok(())
+}
+#[test]
+fn test_spread() -> Result <(),String> {
+    let spread = Spread::of([1.0, 3.0, 3.0, 5.0]);
+    assert_eq!(spread, Spread { min: 1.0, max: 5.0, mean: 3.0, std_dev: f64::sqrt(2.0) });
+    assert_eq!(spread.ratio(), 5.0);
+    let gnomonic = GeometryReport::measure(&Octo::implicit(12));
+    let equal = GeometryReport::measure(&Octo::implicit(12).with_projection(Projection::EqualArea));
+    assert!(equal.area_variation() < gnomonic.area_variation());
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,153 @@
+#[cfg(test)]
+#[path = "./hydrology_tests.rs"]
+mod hydrology_tests;
+use std::{
+    cmp::Ordering,
+    collections::BinaryHeap
+};
+use crate::octo::Octo;
+// Smallest rise forced onto filled depressions so every tile keeps a strictly
+// lower neighbour to drain into.
+const FILL_EPSILON: f64 = 1e-6;
+#[derive(Clone,Copy,Debug,PartialEq)]
+struct Cell {
+    height: f64,
+    tile_id: usize
+}
+impl Eq for Cell {}
+impl Ord for Cell {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.height.total_cmp(&self.height).then(other.tile_id.cmp(&self.tile_id))
+    }
+}
+impl PartialOrd for Cell {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+#[derive(Clone,Debug,PartialEq)]
+pub struct Drainage {
+    pub filled: Vec<f64>,
+    // Lowest edge neighbour on the filled surface, None for the outlets where
+    // water leaves the land.
+    pub downstream: Vec<Option<usize>>,
+    pub accumulation: Vec<f64>,
+    pub basin: Vec<usize>
+}
+impl Drainage {
+    pub fn compute(octo: &Octo, elevation: &[f64], sea_level: f64) -> Drainage {
+        Drainage::compute_weighted(octo, elevation, sea_level, &vec![1.0; octo.tile_count()])
+    }
+    // As compute, but each tile contributes its weight (e.g. rainfall) to the
+    // flow instead of one.
+    pub fn compute_weighted(octo: &Octo, elevation: &[f64], sea_level: f64, weight: &[f64]) -> Drainage {
+        let outlet = outlets(elevation, sea_level);
+        let filled = fill_depressions(octo, elevation, sea_level);
+        let downstream: Vec<Option<usize>> = (0..octo.tile_count()).map(|i| match outlet[i] {
+            true => None,
+            false => octo.neighbours(i).iter()
+                .cloned()
+                .filter(|n| filled[*n] < filled[i])
+                .min_by(|a, b| filled[*a].total_cmp(&filled[*b]).then(a.cmp(b)))
+        }).collect();
+        let mut order: Vec<usize> = (0..octo.tile_count()).collect();
+        order.sort_by(|a, b| filled[*b].total_cmp(&filled[*a]).then(a.cmp(b)));
+        let mut accumulation = weight.to_vec();
+        for &i in order.iter() {
+            if let Some(d) = downstream[i] {
+                accumulation[d] += accumulation[i];
+            }
+        }
+        let mut basin = vec![usize::MAX; octo.tile_count()];
+        let mut outlets = 0;
+        for &i in order.iter().rev() {
+            basin[i] = match downstream[i] {
+                Some(d) => basin[d],
+                None => {
+                    outlets += 1;
+                    outlets - 1
+                }
+            };
+        }
+        Drainage {
+            filled,
+            downstream,
+            accumulation,
+            basin
+        }
+    }
+    pub fn basin_count(&self) -> usize {
+        self.downstream.iter().filter(|d| d.is_none()).count()
+    }
+    // Rivers are runs of tiles whose accumulated flow reaches threshold. Each
+    // path starts at a source and follows the flow until it reaches the sea or
+    // joins a river already traced, in which case it ends on the confluence.
+    pub fn rivers(&self, threshold: f64) -> Vec<Vec<usize>> {
+        let is_river = |i: usize| self.accumulation[i] >= threshold;
+        let mut fed = vec![false; self.filled.len()];
+        for i in 0..self.filled.len() {
+            if let (true, Some(d)) = (is_river(i), self.downstream[i]) {
+                fed[d] = true;
+            }
+        }
+        let mut sources: Vec<usize> = (0..self.filled.len())
+            .filter(|i| is_river(*i) && !fed[*i] && self.downstream[*i].is_some())
+            .collect();
+        sources.sort_by(|a, b| self.accumulation[*b].total_cmp(&self.accumulation[*a]).then(a.cmp(b)));
+        let mut traced = vec![false; self.filled.len()];
+        let mut rivers = Vec::new();
+        for source in sources {
+            let mut path = vec![source];
+            traced[source] = true;
+            let mut current = source;
+            while let Some(d) = self.downstream[current] {
+                path.push(d);
+                if traced[d] {
+                    break;
+                }
+                traced[d] = true;
+                current = d;
+            }
+            rivers.push(path);
+        }
+        rivers
+    }
+}
+// Tiles at or below sea level, or the lowest tile of a world without sea.
+fn outlets(elevation: &[f64], sea_level: f64) -> Vec<bool> {
+    let mut outlet: Vec<bool> = elevation.iter().map(|h| *h <= sea_level).collect();
+    if !outlet.contains(&true) {
+        if let Some(lowest) = (0..elevation.len()).min_by(|a, b| elevation[*a].total_cmp(&elevation[*b])) {
+            outlet[lowest] = true;
+        }
+    }
+    outlet
+}
+// Priority flood from the outlets: tiles are visited lowest first and raised
+// to just above the tile they were reached from, which fills every pit
+// without lowering any tile.
+pub fn fill_depressions(octo: &Octo, elevation: &[f64], sea_level: f64) -> Vec<f64> {
+    let mut filled = elevation.to_vec();
+    let mut done = outlets(elevation, sea_level);
+    let mut heap: BinaryHeap<Cell> = done.iter()
+        .enumerate()
+        .filter(|(_, o)| **o)
+        .map(|(tile_id, _)| Cell {
+            height: elevation[tile_id],
+            tile_id
+        })
+        .collect();
+    while let Some(cell) = heap.pop() {
+        for &n in octo.neighbours(cell.tile_id).iter() {
+            if !done[n] {
+                done[n] = true;
+                filled[n] = filled[n].max(cell.height + FILL_EPSILON);
+                heap.push(Cell {
+                    height: filled[n],
+                    tile_id: n
+                });
+            }
+        }
+    }
+    filled
+}
\ No newline at end of file
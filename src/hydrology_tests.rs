@@ -0,0 +1,77 @@
+#[cfg(test)]
+use crate::{
+    hydrology::Drainage,
+    octo::Octo,
+    terrain::{
+        Terrain,
+        TerrainConfig
+    }
+};
+#[test]
+fn test_drainage_reaches_the_sea() -> Result <(),String> {
+    let octo = Octo::new(16);
+    let terrain = Terrain::generate(&octo, &TerrainConfig {
+        seed: 9,
+        continent_fraction: 0.4,
+        ..TerrainConfig::default()
+    });
+    let drainage = Drainage::compute(&octo, &terrain.elevation, terrain.sea_level);
+    let mut outflow = 0.0;
+    for i in 0..octo.tile_count() {
+        assert!(drainage.filled[i] >= terrain.elevation[i]);
+        match drainage.downstream[i] {
+            Some(d) => {
+                assert!(drainage.filled[d] < drainage.filled[i]);
+                assert!(octo.neighbours(i).contains(&d));
+                assert_eq!(drainage.basin[i], drainage.basin[d]);
+            },
+            None => {
+                assert!(!terrain.is_land(i));
+                outflow += drainage.accumulation[i];
+            }
+        }
+    }
+    assert_eq!(outflow, octo.tile_count() as f64);
+    assert!(drainage.basin.iter().all(|b| *b < drainage.basin_count()));
+    Ok(())
+}
+#[test]
+fn test_pit_is_filled() -> Result <(),String> {
+    let octo = Octo::new(4);
+    let mut elevation = vec![5.0; octo.tile_count()];
+    elevation[0] = -1.0;
+    elevation[10] = 1.0;
+    let drainage = Drainage::compute(&octo, &elevation, 0.0);
+    assert_eq!(drainage.basin_count(), 1);
+    assert!(drainage.filled[10] >= 5.0);
+    assert_eq!(drainage.accumulation[0], octo.tile_count() as f64);
+    let dry = Drainage::compute(&octo, &vec![2.0; octo.tile_count()], 0.0);
+    assert_eq!(dry.basin_count(), 1);
+    assert!(dry.filled.iter().all(|h| *h >= 2.0));
+    assert_eq!(dry.accumulation.iter().cloned().fold(0.0, f64::max), octo.tile_count() as f64);
+    Ok(())
+}
+#[test]
+fn test_rivers_follow_flow() -> Result <(),String> {
+    let octo = Octo::new(24);
+    let terrain = Terrain::generate(&octo, &TerrainConfig {
+        seed: 21,
+        ..TerrainConfig::default()
+    });
+    let drainage = Drainage::compute(&octo, &terrain.elevation, terrain.sea_level);
+    let rivers = drainage.rivers(20.0);
+    assert!(!rivers.is_empty());
+    let face_base = 24 * 24;
+    let mut crosses_face = false;
+    for river in rivers.iter() {
+        assert!(river.len() >= 2);
+        for pair in river.windows(2) {
+            assert_eq!(drainage.downstream[pair[0]], Some(pair[1]));
+            crosses_face |= pair[0] / face_base != pair[1] / face_base;
+        }
+        let mouth = river[river.len() - 1];
+        assert!(drainage.downstream[mouth].is_none() || rivers.iter().any(|r| r[..r.len() - 1].contains(&mouth)));
+    }
+    assert!(crosses_face);
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,26 @@
+pub mod tile;
+pub mod octo;
+pub mod world;
+pub mod mapped;
+pub mod sphere;
+pub mod projection;
+pub mod geometry;
+pub mod raster;
+pub mod unfold;
+pub mod symmetry;
+pub mod cursor;
+pub mod coords;
+pub mod classify;
+pub mod bands;
+pub mod rng;
+pub mod noise;
+pub mod terrain;
+pub mod tectonics;
+pub mod hydrology;
+pub mod climate;
+pub mod automaton;
+pub mod diffusion;
+pub mod components;
+pub mod voronoi;
+pub mod region;
+pub mod sampling;
\ No newline at end of file
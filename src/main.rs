@@ -1,9 +1,7 @@
-mod tile;
-mod octo;
+use octo_sphere::octo::Octo;
 
 fn main() {
     let size = 5;
-    use octo::Octo;
     let octo = Octo::new(size);
     octo.display();
 }
\ No newline at end of file
@@ -0,0 +1,362 @@
+// Lazy, memory-mapped access to the layers of a world file.
+// Only raw (uncompressed) layers can be mapped. Values are addressed by
+// tile_id, which is face major, so all the tiles of a face sit next to each
+// other on disk and face views touch a single contiguous range.
+#[cfg(test)]
+#[path = "./mapped_tests.rs"]
+mod mapped_tests;
+use std::{
+    fs::{
+        File,
+        OpenOptions
+    },
+    io::{
+        Seek,
+        SeekFrom,
+        Write
+    },
+    marker::PhantomData,
+    ops::Range,
+    path::Path
+};
+use memmap2::{
+    Mmap,
+    MmapMut
+};
+use crate::world::{
+    checked_tile_count,
+    crc32,
+    LayerKind,
+    WorldError,
+    ENCODING_RAW,
+    FLAG_CHECKSUM,
+    FORMAT_VERSION,
+    MAGIC
+};
+pub trait TileValue: Copy + 'static {
+    const KIND: LayerKind;
+    fn read(bytes: &[u8]) -> Self;
+    fn write(self, bytes: &mut [u8]);
+}
+macro_rules! tile_value {
+    ($t:ty, $kind:expr) => {
+        impl TileValue for $t {
+            const KIND: LayerKind = $kind;
+            fn read(bytes: &[u8]) -> Self {
+                let mut b = [0; std::mem::size_of::<$t>()];
+                b.copy_from_slice(bytes);
+                <$t>::from_le_bytes(b)
+            }
+            fn write(self, bytes: &mut [u8]) {
+                bytes.copy_from_slice(&self.to_le_bytes());
+            }
+        }
+    };
+}
+tile_value!(u8, LayerKind::U8);
+tile_value!(u16, LayerKind::U16);
+tile_value!(u32, LayerKind::U32);
+tile_value!(i32, LayerKind::I32);
+tile_value!(f32, LayerKind::F32);
+tile_value!(f64, LayerKind::F64);
+#[derive(Clone,Debug)]
+struct Entry {
+    name: String,
+    kind: LayerKind,
+    raw: bool,
+    payload: Range<usize>,
+    crc: Option<usize>,
+    dirty: bool
+}
+enum Map {
+    ReadOnly(Mmap),
+    Writable(MmapMut)
+}
+impl Map {
+    fn bytes(&self) -> &[u8] {
+        match self {
+            Map::ReadOnly(m) => m,
+            Map::Writable(m) => m
+        }
+    }
+}
+pub struct MappedWorld {
+    map: Map,
+    face_size: usize,
+    layers: Vec<Entry>
+}
+impl MappedWorld {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<MappedWorld, WorldError> {
+        let file = File::open(path)?;
+        let map = unsafe { Mmap::map(&file)? };
+        MappedWorld::parse(Map::ReadOnly(map))
+    }
+    pub fn open_mut<P: AsRef<Path>>(path: P) -> Result<MappedWorld, WorldError> {
+        let file = OpenOptions::new().read(true).write(true).open(path)?;
+        let map = unsafe { MmapMut::map_mut(&file)? };
+        MappedWorld::parse(Map::Writable(map))
+    }
+    pub fn create<P: AsRef<Path>>(path: P, face_size: usize, layers: &[(&str, LayerKind)], checksums: bool) -> Result<MappedWorld, WorldError> {
+        let tile_count = checked_tile_count(face_size as u64)?;
+        for (i, (name, _)) in layers.iter().enumerate() {
+            if name.len() > u16::MAX as usize {
+                return Err(WorldError::Malformed(format!("layer name {} is too long", name)));
+            }
+            if layers[..i].iter().any(|(other, _)| other == name) {
+                return Err(WorldError::DuplicateLayer(name.to_string()));
+            }
+        }
+        let mut header = Vec::new();
+        header.extend_from_slice(&MAGIC);
+        header.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+        header.extend_from_slice(&(match checksums {
+            true => FLAG_CHECKSUM,
+            false => 0
+        }).to_le_bytes());
+        header.extend_from_slice(&(face_size as u64).to_le_bytes());
+        header.extend_from_slice(&(layers.len() as u32).to_le_bytes());
+        let mut file = OpenOptions::new().read(true).write(true).create(true).truncate(true).open(&path)?;
+        file.write_all(&header)?;
+        for (name, kind) in layers.iter() {
+            let payload = (tile_count * kind.width()) as u64;
+            file.write_all(&(name.len() as u16).to_le_bytes())?;
+            file.write_all(name.as_bytes())?;
+            file.write_all(&[kind.code(), ENCODING_RAW])?;
+            file.write_all(&payload.to_le_bytes())?;
+            let skip = payload + match checksums {
+                true => 4,
+                false => 0
+            };
+            file.seek(SeekFrom::Current(skip as i64))?;
+        }
+        let length = file.stream_position()?;
+        file.set_len(length)?;
+        drop(file);
+        let mut world = MappedWorld::open_mut(path)?;
+        for layer in world.layers.iter_mut() {
+            layer.dirty = true;
+        }
+        world.flush()?;
+        Ok(world)
+    }
+    fn parse(map: Map) -> Result<MappedWorld, WorldError> {
+        let mut cursor = Cursor {
+            bytes: map.bytes(),
+            at: 0
+        };
+        if cursor.take(4)? != MAGIC {
+            return Err(WorldError::BadMagic);
+        }
+        match cursor.u16()? {
+            1 => {},
+            v => return Err(WorldError::UnsupportedVersion(v))
+        }
+        let flags = cursor.u16()?;
+        let face_size = cursor.u64()?;
+        let tile_count = checked_tile_count(face_size)?;
+        let face_size = face_size as usize;
+        let mut layers: Vec<Entry> = Vec::new();
+        for _ in 0..cursor.u32()? {
+            let name_len = cursor.u16()? as usize;
+            let name = String::from_utf8(cursor.take(name_len)?.to_vec()).map_err(|_| WorldError::BadName)?;
+            let kind = LayerKind::from_code(cursor.u8()?)?;
+            let raw = cursor.u8()? == ENCODING_RAW;
+            let length = cursor.u64()? as usize;
+            let start = cursor.at;
+            cursor.take(length)?;
+            if raw && length != tile_count * kind.width() {
+                return Err(WorldError::LengthMismatch {
+                    layer: name,
+                    expected: tile_count,
+                    found: length / kind.width()
+                });
+            }
+            let crc = match flags & FLAG_CHECKSUM {
+                0 => None,
+                _ => {
+                    let at = cursor.at;
+                    cursor.take(4)?;
+                    Some(at)
+                }
+            };
+            if layers.iter().any(|l| l.name == name) {
+                return Err(WorldError::DuplicateLayer(name));
+            }
+            layers.push(Entry {
+                name,
+                kind,
+                raw,
+                payload: start..start + length,
+                crc,
+                dirty: false
+            });
+        }
+        Ok(MappedWorld {
+            map,
+            face_size,
+            layers
+        })
+    }
+    pub fn face_size(&self) -> usize {
+        self.face_size
+    }
+    pub fn tile_count(&self) -> usize {
+        8 * usize::pow(self.face_size, 2)
+    }
+    pub fn layer_names(&self) -> impl Iterator<Item = &str> {
+        self.layers.iter().map(|l| l.name.as_str())
+    }
+    pub fn layer_kind(&self, name: &str) -> Option<LayerKind> {
+        self.layers.iter().find(|l| l.name == name).map(|l| l.kind)
+    }
+    fn entry<T: TileValue>(&self, name: &str) -> Result<usize, WorldError> {
+        let i = self.layers.iter().position(|l| l.name == name)
+            .ok_or_else(|| WorldError::MissingLayer(name.to_string()))?;
+        let entry = &self.layers[i];
+        if !entry.raw {
+            return Err(WorldError::NotRaw(name.to_string()));
+        }
+        if entry.kind != T::KIND {
+            return Err(WorldError::KindMismatch {
+                layer: name.to_string(),
+                expected: T::KIND,
+                found: entry.kind
+            });
+        }
+        Ok(i)
+    }
+    pub fn layer<T: TileValue>(&self, name: &str) -> Result<LayerView<'_, T>, WorldError> {
+        let entry = &self.layers[self.entry::<T>(name)?];
+        Ok(LayerView {
+            bytes: &self.map.bytes()[entry.payload.clone()],
+            face_size: self.face_size,
+            value: PhantomData
+        })
+    }
+    pub fn layer_mut<T: TileValue>(&mut self, name: &str) -> Result<LayerViewMut<'_, T>, WorldError> {
+        let i = self.entry::<T>(name)?;
+        let face_size = self.face_size;
+        let entry = &mut self.layers[i];
+        match &mut self.map {
+            Map::ReadOnly(_) => Err(WorldError::Io(std::io::Error::new(
+                std::io::ErrorKind::PermissionDenied,
+                "world was opened read only"
+            ))),
+            Map::Writable(m) => {
+                entry.dirty = true;
+                Ok(LayerViewMut {
+                    bytes: &mut m[entry.payload.clone()],
+                    face_size,
+                    value: PhantomData
+                })
+            }
+        }
+    }
+    pub fn verify(&self) -> Result<(), WorldError> {
+        let bytes = self.map.bytes();
+        for layer in self.layers.iter().filter(|l| l.raw && !l.dirty) {
+            if let Some(at) = layer.crc {
+                let stored = u32::from_le_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]]);
+                if stored != crc32(&bytes[layer.payload.clone()]) {
+                    return Err(WorldError::ChecksumMismatch(layer.name.clone()));
+                }
+            }
+        }
+        Ok(())
+    }
+    // Rewrites the checksum of every layer handed out mutably since the last
+    // flush, which reads the whole layer, then syncs the mapping to disk.
+    pub fn flush(&mut self) -> Result<(), WorldError> {
+        if let Map::Writable(m) = &mut self.map {
+            for layer in self.layers.iter_mut().filter(|l| l.dirty) {
+                if let Some(at) = layer.crc {
+                    let crc = crc32(&m[layer.payload.clone()]);
+                    m[at..at + 4].copy_from_slice(&crc.to_le_bytes());
+                }
+                layer.dirty = false;
+            }
+            m.flush()?;
+        }
+        Ok(())
+    }
+}
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    at: usize
+}
+impl<'a> Cursor<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], WorldError> {
+        if self.bytes.len() - self.at < n {
+            return Err(WorldError::Malformed("file is truncated".to_string()));
+        }
+        self.at += n;
+        Ok(&self.bytes[self.at - n..self.at])
+    }
+    fn u8(&mut self) -> Result<u8, WorldError> {
+        Ok(self.take(1)?[0])
+    }
+    fn u16(&mut self) -> Result<u16, WorldError> {
+        Ok(u16::read(self.take(2)?))
+    }
+    fn u32(&mut self) -> Result<u32, WorldError> {
+        Ok(u32::read(self.take(4)?))
+    }
+    fn u64(&mut self) -> Result<u64, WorldError> {
+        let b = self.take(8)?;
+        Ok(u64::from_le_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]]))
+    }
+}
+fn face_range(face_size: usize, face_id: usize) -> Range<usize> {
+    let face_base = usize::pow(face_size, 2);
+    face_id * face_base..(face_id + 1) * face_base
+}
+pub struct LayerView<'a, T: TileValue> {
+    bytes: &'a [u8],
+    face_size: usize,
+    value: PhantomData<T>
+}
+impl<'a, T: TileValue> LayerView<'a, T> {
+    pub fn len(&self) -> usize {
+        self.bytes.len() / T::KIND.width()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+    pub fn get(&self, tile_id: usize) -> T {
+        let w = T::KIND.width();
+        T::read(&self.bytes[tile_id * w..(tile_id + 1) * w])
+    }
+    pub fn face(&self, face_id: usize) -> impl Iterator<Item = T> + 'a {
+        let w = T::KIND.width();
+        let range = face_range(self.face_size, face_id);
+        self.bytes[range.start * w..range.end * w].chunks_exact(w).map(T::read)
+    }
+}
+pub struct LayerViewMut<'a, T: TileValue> {
+    bytes: &'a mut [u8],
+    face_size: usize,
+    value: PhantomData<T>
+}
+impl<'a, T: TileValue> LayerViewMut<'a, T> {
+    pub fn len(&self) -> usize {
+        self.bytes.len() / T::KIND.width()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+    pub fn get(&self, tile_id: usize) -> T {
+        let w = T::KIND.width();
+        T::read(&self.bytes[tile_id * w..(tile_id + 1) * w])
+    }
+    pub fn set(&mut self, tile_id: usize, value: T) {
+        let w = T::KIND.width();
+        value.write(&mut self.bytes[tile_id * w..(tile_id + 1) * w]);
+    }
+    pub fn fill_face(&mut self, face_id: usize, value: T) {
+        let w = T::KIND.width();
+        let range = face_range(self.face_size, face_id);
+        for chunk in self.bytes[range.start * w..range.end * w].chunks_exact_mut(w) {
+            value.write(chunk);
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,91 @@
+#[cfg(test)]
+use crate::{
+    mapped::MappedWorld,
+    world::{
+        Compression,
+        LayerData,
+        LayerKind,
+        World,
+        WorldError,
+        WriteOptions
+    }
+};
+#[cfg(test)]
+fn temp_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("octo_sphere_{}_{}.octw", name, std::process::id()))
+}
+#[test]
+fn test_mapped_reads_world_file() -> Result <(),String> {
+    let path = temp_path("read");
+    let mut world = World::new(3);
+    world.add_layer("height", LayerData::F32((0..72).map(|i| i as f32).collect())).map_err(|e| e.to_string())?;
+    world.add_layer("plate", LayerData::U16((0..72).map(|i| (i / 9) as u16).collect())).map_err(|e| e.to_string())?;
+    world.save(&path, WriteOptions::default()).map_err(|e| e.to_string())?;
+    let mapped = MappedWorld::open(&path).map_err(|e| e.to_string())?;
+    mapped.verify().map_err(|e| e.to_string())?;
+    let height = mapped.layer::<f32>("height").map_err(|e| e.to_string())?;
+    assert_eq!(height.len(), 72);
+    assert_eq!(height.get(41), 41.0);
+    let plate = mapped.layer::<u16>("plate").map_err(|e| e.to_string())?;
+    assert_eq!(plate.face(5).collect::<Vec<u16>>(), (45..54).map(|i| (i / 9) as u16).collect::<Vec<u16>>());
+    assert!(matches!(mapped.layer::<u8>("height"), Err(WorldError::KindMismatch { .. })));
+    assert!(matches!(mapped.layer::<u8>("rain"), Err(WorldError::MissingLayer(_))));
+    std::fs::remove_file(&path).map_err(|e| e.to_string())?;
+    Ok(())
+}
+#[test]
+fn test_mapped_writes_round_trip() -> Result <(),String> {
+    let path = temp_path("write");
+    {
+        let mut mapped = MappedWorld::create(&path, 4, &[("biome", LayerKind::U8), ("heat", LayerKind::F64)], true)
+            .map_err(|e| e.to_string())?;
+        let mut heat = mapped.layer_mut::<f64>("heat").map_err(|e| e.to_string())?;
+        heat.set(100, -2.5);
+        heat.fill_face(2, 7.0);
+        mapped.layer_mut::<u8>("biome").map_err(|e| e.to_string())?.set(0, 9);
+        mapped.flush().map_err(|e| e.to_string())?;
+    }
+    let world = World::open(&path).map_err(|e| e.to_string())?;
+    match world.layer("heat") {
+        Some(LayerData::F64(heat)) => {
+            assert_eq!(heat[100], -2.5);
+            assert!(heat[32..48].iter().all(|h| *h == 7.0));
+            assert_eq!(heat[31], 0.0);
+        },
+        other => return Err(format!("{:?}", other))
+    }
+    assert_eq!(world.layer("biome").map(|b| b.len()), Some(128));
+    std::fs::remove_file(&path).map_err(|e| e.to_string())?;
+    Ok(())
+}
+#[test]
+fn test_mapped_rejects_compressed_layer() -> Result <(),String> {
+    let path = temp_path("compressed");
+    let mut world = World::new(2);
+    world.add_layer("ocean", LayerData::U8(vec![1; 32])).map_err(|e| e.to_string())?;
+    world.save(&path, WriteOptions {
+        compression: Compression::RunLength,
+        checksums: false
+    }).map_err(|e| e.to_string())?;
+    let mapped = MappedWorld::open(&path).map_err(|e| e.to_string())?;
+    assert!(matches!(mapped.layer::<u8>("ocean"), Err(WorldError::NotRaw(_))));
+    std::fs::remove_file(&path).map_err(|e| e.to_string())?;
+    Ok(())
+}
+#[test]
+fn test_mapped_rejects_bad_input() -> Result <(),String> {
+    let path = temp_path("bad_input");
+    let long = "x".repeat(70000);
+    assert!(matches!(MappedWorld::create(&path, 2, &[("a", LayerKind::U8), (&long, LayerKind::U8)], true), Err(WorldError::Malformed(_))));
+    assert!(matches!(MappedWorld::create(&path, 2, &[("a", LayerKind::U8), ("a", LayerKind::F32)], true), Err(WorldError::DuplicateLayer(_))));
+    assert!(!path.exists());
+    let mut bytes = Vec::new();
+    let mut world = World::new(2);
+    world.add_layer("ocean", LayerData::U8(vec![1; 32])).map_err(|e| e.to_string())?;
+    world.write(&mut bytes, WriteOptions::default()).map_err(|e| e.to_string())?;
+    bytes[8..16].copy_from_slice(&(1u64 << 40).to_le_bytes());
+    std::fs::write(&path, &bytes).map_err(|e| e.to_string())?;
+    assert!(matches!(MappedWorld::open(&path), Err(WorldError::Malformed(_))));
+    std::fs::remove_file(&path).map_err(|e| e.to_string())?;
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,71 @@
+// Seeded three dimensional gradient noise. Sampling in 3D at points on the
+// sphere means the field has no seams at face edges or octahedron corners.
+use crate::{
+    rng::Rng,
+    sphere::Vec3
+};
+#[derive(Clone,Debug,PartialEq)]
+pub struct Noise {
+    permutation: Vec<usize>
+}
+const GRADIENTS: [Vec3; 12] = [
+    [1.0, 1.0, 0.0], [-1.0, 1.0, 0.0], [1.0, -1.0, 0.0], [-1.0, -1.0, 0.0],
+    [1.0, 0.0, 1.0], [-1.0, 0.0, 1.0], [1.0, 0.0, -1.0], [-1.0, 0.0, -1.0],
+    [0.0, 1.0, 1.0], [0.0, -1.0, 1.0], [0.0, 1.0, -1.0], [0.0, -1.0, -1.0]
+];
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+fn lerp(t: f64, a: f64, b: f64) -> f64 {
+    a + t * (b - a)
+}
+impl Noise {
+    pub fn new(seed: u64) -> Noise {
+        let mut rng = Rng::new(seed);
+        let mut permutation: Vec<usize> = (0..256).collect();
+        rng.shuffle(&mut permutation);
+        permutation.extend_from_within(..);
+        Noise {
+            permutation
+        }
+    }
+    // Roughly in [-1, 1].
+    pub fn sample(&self, p: Vec3) -> f64 {
+        let cell = [p[0].floor(), p[1].floor(), p[2].floor()];
+        let f = [p[0] - cell[0], p[1] - cell[1], p[2] - cell[2]];
+        let c = [
+            (cell[0] as i64 & 255) as usize,
+            (cell[1] as i64 & 255) as usize,
+            (cell[2] as i64 & 255) as usize
+        ];
+        let perm = &self.permutation;
+        let corner = |dx: usize, dy: usize, dz: usize| {
+            let hash = perm[perm[perm[c[0] + dx] + c[1] + dy] + c[2] + dz];
+            let g = GRADIENTS[hash % 12];
+            g[0] * (f[0] - dx as f64) + g[1] * (f[1] - dy as f64) + g[2] * (f[2] - dz as f64)
+        };
+        let (u, v, w) = (fade(f[0]), fade(f[1]), fade(f[2]));
+        lerp(w,
+            lerp(v, lerp(u, corner(0, 0, 0), corner(1, 0, 0)), lerp(u, corner(0, 1, 0), corner(1, 1, 0))),
+            lerp(v, lerp(u, corner(0, 0, 1), corner(1, 0, 1)), lerp(u, corner(0, 1, 1), corner(1, 1, 1))))
+    }
+    // Fractal sum of octaves, normalized back to roughly [-1, 1].
+    pub fn fractal(&self, p: Vec3, octaves: usize, frequency: f64, lacunarity: f64, persistence: f64) -> f64 {
+        let (mut sum, mut amplitude, mut total, mut frequency) = (0.0, 1.0, 0.0, frequency);
+        for octave in 0..octaves {
+            let offset = octave as f64 * 17.31;
+            sum += amplitude * self.sample([
+                p[0] * frequency + offset,
+                p[1] * frequency + offset,
+                p[2] * frequency + offset
+            ]);
+            total += amplitude;
+            amplitude *= persistence;
+            frequency *= lacunarity;
+        }
+        match total {
+            t if t > 0.0 => sum / t,
+            _ => 0.0
+        }
+    }
+}
\ No newline at end of file
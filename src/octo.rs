@@ -1,15 +1,20 @@
 #[cfg(test)]
 #[path = "./octo_tests.rs"]
 mod octo_tests;
-use crate::tile::{
-    Tile,
-    TileType,
-    Direction
+use crate::{
+    projection::Projection,
+    tile::{
+        Tile,
+        TileType,
+        Direction
+    }
 };
 #[derive(Clone,Debug,PartialEq)]
 pub struct Octo {
     face_size: usize,
-    tile_grid: Vec<Tile>
+    tile_grid: Vec<Tile>,
+    step_table: Option<Vec<usize>>,
+    projection: Projection
 }
 #[allow(dead_code)]
 impl Octo {
@@ -32,9 +37,121 @@ impl Octo {
         }
         Octo {
             face_size,
-            tile_grid
+            tile_grid,
+            step_table: None,
+            projection: Projection::Gnomonic
+        }
+    }
+    // Builds an octahedron without materializing tile_grid, tile types are
+    // derived from the row and index parity whenever they are needed.
+    pub fn implicit(face_size: usize) -> Octo {
+        Octo {
+            face_size,
+            tile_grid: Vec::new(),
+            step_table: None,
+            projection: Projection::Gnomonic
+        }
+    }
+    // Precomputes every step so that step becomes a single lookup, each entry
+    // packs the arrival tile and direction as tile_id * 6 + direction index.
+    pub fn with_step_table(mut self) -> Octo {
+        let mut table = Vec::with_capacity(self.tile_count() * 6);
+        for i in 0..self.tile_count() {
+            for d in 0..6 {
+                let (tile_id, direction) = self.compute_step((i, Direction::from_index(d)));
+                table.push(tile_id * 6 + direction.index());
+            }
+        }
+        self.step_table = Some(table);
+        self
+    }
+    // Chooses how faces are laid onto the sphere for every centroid, vertex
+    // and lookup query, stepping is unaffected.
+    pub fn with_projection(mut self, projection: Projection) -> Octo {
+        self.projection = projection;
+        self
+    }
+    pub fn projection(&self) -> Projection {
+        self.projection
+    }
+    pub fn has_step_table(&self) -> bool {
+        self.step_table.is_some()
+    }
+    pub fn is_implicit(&self) -> bool {
+        self.tile_grid.len() != self.tile_count()
+    }
+    pub fn face_size(&self) -> usize {
+        self.face_size
+    }
+    pub fn tile_count(&self) -> usize {
+        8 * usize::pow(self.face_size, 2)
+    }
+    pub fn tile_type(&self, tile_id: usize) -> TileType {
+        match self.tile_grid.get(tile_id) {
+            Some(tile) => tile.tile_type.clone(),
+            None => {
+                let index_id = tile_id % usize::pow(self.face_size, 2);
+                match (index_id - usize::pow(isqrt(index_id), 2)) % 2 {
+                    0 => TileType::Point,
+                    _ => TileType::Flat
+                }
+            }
         }
     }
+    pub fn tile(&self, tile_id: usize) -> Tile {
+        match self.tile_grid.get(tile_id) {
+            Some(tile) => tile.clone(),
+            None => Tile {
+                tile_type: self.tile_type(tile_id),
+                tile_id
+            }
+        }
+    }
+    // The three directions that cross an edge of the tile rather than a
+    // vertex, lower faces are mirrored so the signs flip.
+    pub fn edge_directions(&self, tile_id: usize) -> [Direction; 3] {
+        let directions = match self.tile_type(tile_id) {
+            TileType::Point => [Direction::NegZ, Direction::PosY, Direction::NegX],
+            TileType::Flat => [Direction::PosX, Direction::NegY, Direction::PosZ]
+        };
+        match tile_id / usize::pow(self.face_size, 2) {
+            face_id if face_id > 3 => [
+                -directions[0].clone(),
+                -directions[1].clone(),
+                -directions[2].clone()
+            ],
+            _ => directions
+        }
+    }
+    pub fn neighbours(&self, tile_id: usize) -> [usize; 3] {
+        let [a, b, c] = self.edge_directions(tile_id);
+        [
+            self.step((tile_id, a)).0,
+            self.step((tile_id, b)).0,
+            self.step((tile_id, c)).0
+        ]
+    }
+    // Every tile sharing at least one corner with the tile, twelve of them
+    // except around the six octahedron vertices where only four tiles meet.
+    pub fn vertex_neighbours(&self, tile_id: usize) -> Vec<usize> {
+        let mut neighbours = Vec::with_capacity(12);
+        self.vertex_neighbours_into(tile_id, &mut neighbours);
+        neighbours
+    }
+    // As vertex_neighbours but refilling the caller's buffer.
+    pub fn vertex_neighbours_into(&self, tile_id: usize, neighbours: &mut Vec<usize>) {
+        neighbours.clear();
+        for &n in self.neighbours(tile_id).iter() {
+            neighbours.push(n);
+            neighbours.extend(self.neighbours(n).iter().filter(|m| **m != tile_id));
+        }
+        let [a, b, c] = self.edge_directions(tile_id);
+        for direction in [-a, -b, -c].iter() {
+            neighbours.push(self.step((tile_id, direction.clone())).0);
+        }
+        neighbours.sort_unstable();
+        neighbours.dedup();
+    }
     pub fn display(&self) {
         for i in 0..self.face_size {
             for j in 0..4 {
@@ -48,7 +165,7 @@ impl Octo {
                     print!("     ");
                 }
             }
-            println!("");
+            println!();
         }
         for i in (0..self.face_size).rev() {
             for j in (4..8).rev() {
@@ -62,10 +179,19 @@ impl Octo {
                     print!("     ");
                 }
             }
-            println!("");
+            println!();
         }
     }
     pub fn step(&self, vector: (usize, Direction)) -> (usize, Direction) {
+        match &self.step_table {
+            Some(table) => {
+                let packed = table[vector.0 * 6 + vector.1.index()];
+                (packed / 6, Direction::from_index(packed % 6))
+            },
+            None => self.compute_step(vector)
+        }
+    }
+    fn compute_step(&self, vector: (usize, Direction)) -> (usize, Direction) {
         let face_base = usize::pow(self.face_size, 2);
         let tile_id = vector.0;
         let face_id = tile_id / face_base;
@@ -75,7 +201,7 @@ impl Octo {
             _ => orientation.clone()
         };
         let index_id = tile_id - face_id * face_base;
-        let h = (index_id as f32).sqrt() as usize;
+        let h = isqrt(index_id);
         (match correction {
             Direction::PosX =>
                 match index_id {
@@ -120,7 +246,7 @@ impl Octo {
                     _ => tile_id + 2 * (h + 1)
                 },
             Direction::PosY => 
-                match self.tile_grid[tile_id].tile_type {
+                match self.tile_type(tile_id) {
                     TileType::Point => match index_id {
                         0 => {
                             orientation = !orientation;
@@ -146,14 +272,14 @@ impl Octo {
                             face_base * match face_id {
                                     3 => 0,
                                     7 => 4,
-                                    _ => (face_id + 1)
+                                    _ => face_id + 1
                                 } + usize::pow(h, 2) + 1
                         },
                         _ => tile_id - 2 * h + 2
                     }
                 },
             Direction::NegY => 
-                match self.tile_grid[tile_id].tile_type {
+                match self.tile_type(tile_id) {
                     TileType::Flat => tile_id - 1,
                     _ => match index_id {
                         _ if index_id == usize::pow(self.face_size - 1,2) => {
@@ -181,7 +307,7 @@ impl Octo {
                     }
                 },
             Direction::PosZ => 
-                match self.tile_grid[tile_id].tile_type {
+                match self.tile_type(tile_id) {
                     TileType::Point => match index_id {
                         _ if index_id == face_base - 1 => {
                             face_base * match face_id {
@@ -211,7 +337,7 @@ impl Octo {
                     _ => tile_id + 1
                 },
             Direction::NegZ => 
-                match self.tile_grid[tile_id].tile_type {
+                match self.tile_type(tile_id) {
                     TileType::Point => match index_id {
                         0 => {
                             orientation = !orientation;
@@ -245,4 +371,14 @@ impl Octo {
                 }
         }, orientation)
     }
+}
+pub(crate) fn isqrt(n: usize) -> usize {
+    let mut r = (n as f64).sqrt() as usize;
+    while r * r > n {
+        r -= 1;
+    }
+    while (r + 1) * (r + 1) <= n {
+        r += 1;
+    }
+    r
 }
\ No newline at end of file
@@ -1,7 +1,10 @@
 #[cfg(test)]
 use crate::{
     octo::Octo,
-    tile::Direction
+    tile::{
+        Direction,
+        TileType
+    }
 };
 #[test]
 fn test_step_size_1() -> Result <(),String> {
@@ -731,4 +734,78 @@ fn test_walk_size_4() -> Result <(),String> {
 fn test_walk_size_5() -> Result <(),String> {
     assert_eq!(auto_walk(5),Ok(()));
     Ok(())
+}#[test]
+fn test_implicit_matches_materialized() -> Result <(),String> {
+    for size in 1..8 {
+        let octo = Octo::new(size);
+        let implicit = Octo::implicit(size);
+        assert!(implicit.is_implicit());
+        assert!(!octo.is_implicit());
+        for i in 0..octo.tile_count() {
+            assert_eq!(implicit.tile(i), octo.tile(i));
+            for d in 0..6 {
+                let direction = match d {
+                    0 => Direction::PosX,
+                    1 => Direction::PosY,
+                    2 => Direction::PosZ,
+                    3 => Direction::NegX,
+                    4 => Direction::NegY,
+                    _ => Direction::NegZ,
+                };
+                assert_eq!(implicit.step((i, direction.clone())), octo.step((i, direction)));
+            }
+        }
+    }
+    Ok(())
+}
+#[test]
+fn test_implicit_large_face() -> Result <(),String> {
+    let size = 3000;
+    let octo = Octo::implicit(size);
+    let face_base = usize::pow(size, 2);
+    assert_eq!(octo.tile_count(), 8 * face_base);
+    assert_eq!(octo.tile_type(5 * face_base + usize::pow(2999, 2)), TileType::Point);
+    assert_eq!(octo.tile_type(5 * face_base + usize::pow(2999, 2) + 1), TileType::Flat);
+    assert_eq!(octo.tile_type(6 * face_base - 1), TileType::Point);
+    assert_eq!(octo.step((0, Direction::PosX)), (2 * face_base, Direction::NegX));
+    assert_eq!(octo.step((face_base - 1, Direction::PosZ)), (6 * face_base + usize::pow(2999, 2) + 2 * 2999, Direction::PosZ));
+    Ok(())
+}#[test]
+fn test_step_table_matches_computed() -> Result <(),String> {
+    for size in 1..8 {
+        let octo = Octo::new(size);
+        let table = Octo::new(size).with_step_table();
+        assert!(table.has_step_table());
+        for i in 0..octo.tile_count() {
+            for d in 0..6 {
+                let direction = Direction::from_index(d);
+                assert_eq!(table.step((i, direction.clone())), octo.step((i, direction)));
+            }
+        }
+    }
+    Ok(())
+}
+#[test]
+fn test_step_exact_rows_past_f32() -> Result <(),String> {
+    let size = 5000;
+    let octo = Octo::implicit(size);
+    let face_base = usize::pow(size, 2);
+    assert_eq!(octo.step((face_base - 1, Direction::PosX)), (face_base + usize::pow(size - 2, 2), Direction::PosY));
+    assert_eq!(octo.step((face_base - 2, Direction::PosX)), (face_base - 2 - 2 * (size - 1), Direction::PosX));
+    assert_eq!(octo.step((usize::pow(size - 1, 2), Direction::PosX)), (3 * face_base + usize::pow(size - 1, 2) - 1, Direction::NegZ));
+    Ok(())
+}#[test]
+fn test_neighbours_are_mutual() -> Result <(),String> {
+    for size in 1..8 {
+        let octo = Octo::new(size);
+        for i in 0..octo.tile_count() {
+            let neighbours = octo.neighbours(i);
+            assert!(!neighbours.contains(&i));
+            assert!(neighbours[0] != neighbours[1] && neighbours[1] != neighbours[2] && neighbours[0] != neighbours[2]);
+            for n in neighbours.iter() {
+                assert!(octo.neighbours(*n).contains(&i), "{} -> {} in size {}", i, n, size);
+            }
+        }
+    }
+    Ok(())
 }
\ No newline at end of file
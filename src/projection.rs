@@ -0,0 +1,120 @@
+#[cfg(test)]
+#[path = "./projection_tests.rs"]
+mod projection_tests;
+use std::f64::consts::FRAC_PI_2;
+use crate::{
+    geometry::GeometryReport,
+    octo::Octo,
+    sphere::{
+        dot,
+        normalize,
+        Vec3
+    }
+};
+// How barycentric positions on a flat face are carried onto the sphere. The
+// weights are for the pole and the west and east equatorial corners.
+#[derive(Clone,Copy,Debug,Default,PartialEq)]
+pub enum Projection {
+    // Straight out from the centre of the sphere, tiles near face centres end
+    // up about five times larger than those near the octahedron vertices.
+    #[default]
+    Gnomonic,
+    // Weights pass through a sine first, so every face edge is split evenly
+    // along its great circle.
+    Slerp,
+    // Octahedral equal-area map: the distance from the pole sets the height
+    // and the position along the row sets the longitude, so equal areas on a
+    // face stay equal on the sphere.
+    EqualArea
+}
+fn combine(corners: [Vec3; 3], w: Vec3) -> Vec3 {
+    let mut p = [0.0; 3];
+    for (axis, value) in p.iter_mut().enumerate() {
+        *value = w[0] * corners[0][axis] + w[1] * corners[1][axis] + w[2] * corners[2][axis];
+    }
+    p
+}
+impl Projection {
+    pub(crate) fn to_sphere(self, corners: [Vec3; 3], w: Vec3) -> Vec3 {
+        match self {
+            Projection::Gnomonic => normalize(combine(corners, w)),
+            Projection::Slerp => normalize(combine(corners, [
+                (w[0] * FRAC_PI_2).sin(),
+                (w[1] * FRAC_PI_2).sin(),
+                (w[2] * FRAC_PI_2).sin()
+            ])),
+            Projection::EqualArea => {
+                let d = 1.0 - w[0];
+                let height = 1.0 - d * d;
+                let phi = match d {
+                    d if d > 0.0 => FRAC_PI_2 * w[2] / d,
+                    _ => 0.0
+                };
+                let r = (1.0 - height * height).max(0.0).sqrt();
+                normalize(combine(corners, [height, r * phi.cos(), r * phi.sin()]))
+            }
+        }
+    }
+    pub(crate) fn to_weights(self, corners: [Vec3; 3], p: Vec3) -> Vec3 {
+        let x = [dot(p, corners[0]).max(0.0), dot(p, corners[1]).max(0.0), dot(p, corners[2]).max(0.0)];
+        match self {
+            Projection::Gnomonic => {
+                let sum = x[0] + x[1] + x[2];
+                [x[0] / sum, x[1] / sum, x[2] / sum]
+            },
+            Projection::Slerp => {
+                let weights = |s: f64| [
+                    (s * x[0]).min(1.0).asin() / FRAC_PI_2,
+                    (s * x[1]).min(1.0).asin() / FRAC_PI_2,
+                    (s * x[2]).min(1.0).asin() / FRAC_PI_2
+                ];
+                let (mut low, mut high) = (0.0, 1.0 / x[0].max(x[1]).max(x[2]));
+                for _ in 0..60 {
+                    let mid = (low + high) / 2.0;
+                    let w = weights(mid);
+                    match w[0] + w[1] + w[2] < 1.0 {
+                        true => low = mid,
+                        false => high = mid
+                    }
+                }
+                let w = weights((low + high) / 2.0);
+                let sum = w[0] + w[1] + w[2];
+                [w[0] / sum, w[1] / sum, w[2] / sum]
+            },
+            Projection::EqualArea => {
+                let d = (1.0 - x[0].min(1.0)).sqrt();
+                let east = d * x[2].atan2(x[1]) / FRAC_PI_2;
+                [1.0 - d, d - east, east]
+            }
+        }
+    }
+}
+#[derive(Clone,Debug,PartialEq)]
+pub struct ProjectionReport {
+    pub geometry: GeometryReport,
+    // Largest ratio of a tile's longest edge to its shortest.
+    pub shape_distortion: f64
+}
+impl ProjectionReport {
+    pub fn measure(octo: &Octo) -> ProjectionReport {
+        let shape_distortion = (0..octo.tile_count())
+            .map(|t| {
+                let edges = octo.edge_lengths(t);
+                edges[0].max(edges[1]).max(edges[2]) / edges[0].min(edges[1]).min(edges[2])
+            })
+            .fold(1.0, f64::max);
+        ProjectionReport {
+            geometry: GeometryReport::measure(octo),
+            shape_distortion
+        }
+    }
+    pub fn for_sizes(projection: Projection, sizes: &[usize]) -> Vec<ProjectionReport> {
+        sizes.iter()
+            .map(|s| ProjectionReport::measure(&Octo::implicit(*s).with_projection(projection)))
+            .collect()
+    }
+    // Largest tile area over the smallest.
+    pub fn area_distortion(&self) -> f64 {
+        self.geometry.area_ratio()
+    }
+}
\ No newline at end of file
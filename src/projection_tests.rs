@@ -0,0 +1,55 @@
+#[cfg(test)]
+use crate::{
+    octo::Octo,
+    projection::{
+        Projection,
+        ProjectionReport
+    },
+    sphere::dot
+};
+#[cfg(test)]
+const PROJECTIONS: [Projection; 3] = [Projection::Gnomonic, Projection::Slerp, Projection::EqualArea];
+#[test]
+fn test_centroid_round_trip() -> Result <(),String> {
+    for projection in PROJECTIONS {
+        let octo = Octo::new(7).with_projection(projection);
+        for t in 0..octo.tile_count() {
+            assert_eq!(octo.tile_at_point(octo.centroid(t)), t, "{:?}", projection);
+        }
+    }
+    Ok(())
+}
+#[test]
+fn test_neighbours_share_edge() -> Result <(),String> {
+    for projection in PROJECTIONS {
+        let octo = Octo::new(6).with_projection(projection);
+        for t in 0..octo.tile_count() {
+            let here = octo.vertices(t);
+            for n in octo.neighbours(t) {
+                let shared = octo.vertices(n).iter()
+                    .filter(|v| here.iter().any(|h| dot(**v, *h) > 1.0 - 1e-12))
+                    .count();
+                assert_eq!(shared, 2, "{:?}: tiles {} and {}", projection, t, n);
+            }
+        }
+    }
+    Ok(())
+}
+#[test]
+fn test_areas_cover_sphere() -> Result <(),String> {
+    for report in PROJECTIONS.iter().flat_map(|p| ProjectionReport::for_sizes(*p, &[4, 9])) {
+        let geometry = report.geometry;
+        let total = geometry.area.mean * (8 * usize::pow(geometry.face_size, 2)) as f64;
+        assert!((total - 4.0 * std::f64::consts::PI).abs() < 1e-9, "{:?} at {} covers {}", geometry.projection, geometry.face_size, total);
+        assert!(report.shape_distortion >= 1.0);
+    }
+    Ok(())
+}
+#[test]
+fn test_equal_area_reduces_distortion() -> Result <(),String> {
+    let gnomonic = ProjectionReport::measure(&Octo::implicit(16));
+    let equal = ProjectionReport::measure(&Octo::implicit(16).with_projection(Projection::EqualArea));
+    assert!(equal.area_distortion() < gnomonic.area_distortion(), "equal area {} against gnomonic {}", equal.area_distortion(), gnomonic.area_distortion());
+    assert!(equal.area_distortion() < 2.0, "equal area ratio {}", equal.area_distortion());
+    Ok(())
+}
\ No newline at end of file
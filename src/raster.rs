@@ -0,0 +1,260 @@
+// Flat map images of per-tile layers, for comparing against gridded data.
+// Rendering looks up the tile under every pixel centre. Images are written as
+// binary PPM or as PNG using stored deflate blocks, so no compression crate
+// is needed, PPM can also be read back. Importing averages the pixels of an
+// equirectangular grid into each tile, weighted by the solid angle each
+// covers.
+#[cfg(test)]
+#[path = "./raster_tests.rs"]
+mod raster_tests;
+use std::{
+    f64::consts::{
+        FRAC_PI_2,
+        PI,
+        SQRT_2
+    },
+    fs::File,
+    io::{
+        self,
+        BufRead,
+        BufReader,
+        BufWriter,
+        Read,
+        Write
+    },
+    path::Path
+};
+use crate::{
+    octo::Octo,
+    world::crc32_update
+};
+pub const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum MapProjection {
+    Equirectangular,
+    // Equal-area ellipse twice as wide as it is tall.
+    Mollweide
+}
+impl MapProjection {
+    // Latitude and longitude under the point (x, y) of the image, both in
+    // [0, 1) from the top left, or None outside the map.
+    pub fn to_lat_lon(self, x: f64, y: f64) -> Option<(f64, f64)> {
+        match self {
+            MapProjection::Equirectangular => Some((FRAC_PI_2 - y * PI, x * 2.0 * PI - PI)),
+            MapProjection::Mollweide => {
+                let (mx, my) = ((2.0 * x - 1.0) * 2.0 * SQRT_2, (1.0 - 2.0 * y) * SQRT_2);
+                let theta = (my / SQRT_2).clamp(-1.0, 1.0).asin();
+                let lon = PI * mx / (2.0 * SQRT_2 * theta.cos());
+                match lon.abs() <= PI {
+                    true => Some((((2.0 * theta + (2.0 * theta).sin()) / PI).clamp(-1.0, 1.0).asin(), lon)),
+                    false => None
+                }
+            }
+        }
+    }
+}
+#[derive(Clone,Debug,PartialEq)]
+pub struct Raster {
+    width: usize,
+    height: usize,
+    pixels: Vec<[u8; 3]>
+}
+impl Raster {
+    pub fn new(width: usize, height: usize) -> Raster {
+        Raster {
+            width,
+            height,
+            pixels: vec![[0; 3]; width * height]
+        }
+    }
+    // Colours every pixel by the tile beneath it, pixels off the map stay
+    // black.
+    pub fn render<F>(octo: &Octo, width: usize, height: usize, projection: MapProjection, colour: F) -> Raster
+    where F: Fn(usize) -> [u8; 3] {
+        let mut raster = Raster::new(width, height);
+        for y in 0..height {
+            for x in 0..width {
+                let centre = ((x as f64 + 0.5) / width as f64, (y as f64 + 0.5) / height as f64);
+                if let Some((lat, lon)) = projection.to_lat_lon(centre.0, centre.1) {
+                    raster.pixels[y * width + x] = colour(octo.tile_at(lat, lon));
+                }
+            }
+        }
+        raster
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    pub fn pixels(&self) -> &[[u8; 3]] {
+        &self.pixels
+    }
+    pub fn get(&self, x: usize, y: usize) -> [u8; 3] {
+        self.pixels[y * self.width + x]
+    }
+    pub fn set(&mut self, x: usize, y: usize, rgb: [u8; 3]) {
+        self.pixels[y * self.width + x] = rgb;
+    }
+    // Mean of the three channels per pixel, scaled to [0, 1].
+    pub fn luminance(&self) -> Vec<f64> {
+        self.pixels.iter()
+            .map(|p| (p[0] as f64 + p[1] as f64 + p[2] as f64) / 765.0)
+            .collect()
+    }
+    pub fn write_ppm<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        write!(writer, "P6\n{} {}\n255\n", self.width, self.height)?;
+        writer.write_all(&self.pixels.concat())
+    }
+    pub fn read_ppm<R: BufRead>(reader: &mut R) -> io::Result<Raster> {
+        let mut fields = Vec::new();
+        let mut token = Vec::new();
+        while fields.len() < 4 {
+            let mut byte = [0];
+            reader.read_exact(&mut byte)?;
+            match byte[0] {
+                b'#' if token.is_empty() => {
+                    let mut comment = Vec::new();
+                    reader.read_until(b'\n', &mut comment)?;
+                },
+                b if b.is_ascii_whitespace() => if !token.is_empty() {
+                    fields.push(String::from_utf8_lossy(&token).into_owned());
+                    token.clear();
+                },
+                b => token.push(b)
+            }
+        }
+        let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message.to_string());
+        if fields[0] != "P6" {
+            return Err(invalid("not a binary PPM"));
+        }
+        let number = |field: &str| field.parse::<usize>().map_err(|_| invalid("bad PPM header"));
+        let (width, height, max) = (number(&fields[1])?, number(&fields[2])?, number(&fields[3])?);
+        if max == 0 || max > 255 {
+            return Err(invalid("only 8 bit PPM is supported"));
+        }
+        let length = width.checked_mul(height)
+            .and_then(|n| n.checked_mul(3))
+            .ok_or_else(|| invalid("PPM dimensions are too large"))?;
+        let mut bytes = Vec::new();
+        reader.by_ref().take(length as u64).read_to_end(&mut bytes)?;
+        if bytes.len() != length {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "PPM pixels are truncated"));
+        }
+        let scale = |v: u8| (v as usize * 255 / max) as u8;
+        Ok(Raster {
+            width,
+            height,
+            pixels: bytes.chunks(3).map(|p| [scale(p[0]), scale(p[1]), scale(p[2])]).collect()
+        })
+    }
+    pub fn write_png<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&PNG_SIGNATURE)?;
+        let mut header = Vec::with_capacity(13);
+        header.extend_from_slice(&(self.width as u32).to_be_bytes());
+        header.extend_from_slice(&(self.height as u32).to_be_bytes());
+        header.extend_from_slice(&[8, 2, 0, 0, 0]);
+        write_chunk(writer, b"IHDR", &header)?;
+        let mut scanlines = Vec::with_capacity(self.height * (1 + self.width * 3));
+        for row in self.pixels.chunks(self.width.max(1)).take(self.height) {
+            scanlines.push(0);
+            scanlines.extend_from_slice(&row.concat());
+        }
+        write_chunk(writer, b"IDAT", &zlib_stored(&scanlines))?;
+        write_chunk(writer, b"IEND", &[])
+    }
+    pub fn save_ppm<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_ppm(&mut writer)?;
+        writer.flush()
+    }
+    pub fn save_png<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_png(&mut writer)?;
+        writer.flush()
+    }
+    pub fn open_ppm<P: AsRef<Path>>(path: P) -> io::Result<Raster> {
+        Raster::read_ppm(&mut BufReader::new(File::open(path)?))
+    }
+}
+fn write_chunk<W: Write>(writer: &mut W, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    writer.write_all(&(data.len() as u32).to_be_bytes())?;
+    writer.write_all(kind)?;
+    writer.write_all(data)?;
+    writer.write_all(&(!crc32_update(crc32_update(!0, kind), data)).to_be_bytes())
+}
+fn zlib_stored(bytes: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = bytes.chunks(0xFFFF).peekable();
+    if blocks.peek().is_none() {
+        out.extend_from_slice(&[1, 0, 0, 0xFF, 0xFF]);
+    }
+    while let Some(block) = blocks.next() {
+        out.push(blocks.peek().is_none() as u8);
+        out.extend_from_slice(&(block.len() as u16).to_le_bytes());
+        out.extend_from_slice(&(!(block.len() as u16)).to_le_bytes());
+        out.extend_from_slice(block);
+    }
+    out.extend_from_slice(&adler32(bytes).to_be_bytes());
+    out
+}
+pub(crate) fn adler32(bytes: &[u8]) -> u32 {
+    let (a, b) = bytes.iter().fold((1u32, 0u32), |(a, b), &v| {
+        let a = (a + v as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+    (b << 16) | a
+}
+// Grey levels spreading the values of a layer from black at its minimum to
+// white at its maximum.
+pub fn grayscale(values: &[f64]) -> impl Fn(usize) -> [u8; 3] + '_ {
+    let low = values.iter().cloned().fold(f64::INFINITY, f64::min);
+    let high = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    move |tile_id| {
+        let level = match high > low {
+            true => ((values[tile_id] - low) / (high - low) * 255.0).round() as u8,
+            false => 0
+        };
+        [level; 3]
+    }
+}
+// Averages an equirectangular grid of samples, row major from the north west
+// corner, into one value per tile. Pixels are split into enough sub-samples
+// to hit every tile, each weighted by the solid angle it covers, and a tile
+// that still catches nothing takes the pixel under its centroid.
+pub fn import_equirectangular(octo: &Octo, width: usize, samples: &[f64]) -> Vec<f64> {
+    assert!(width > 0 && samples.len() >= width, "at least one row of samples");
+    assert_eq!(samples.len() % width, 0, "whole rows of samples");
+    let height = samples.len() / width;
+    let tile_size = (4.0 * PI / octo.tile_count() as f64).sqrt();
+    let pixel_size = (2.0 * PI / width as f64).max(PI / height as f64);
+    let split = ((2.0 * pixel_size / tile_size).ceil() as usize).clamp(1, 16);
+    let mut sum = vec![0.0; octo.tile_count()];
+    let mut weight = vec![0.0; octo.tile_count()];
+    for y in 0..height {
+        for x in 0..width {
+            for sy in 0..split {
+                for sx in 0..split {
+                    let u = (x as f64 + (sx as f64 + 0.5) / split as f64) / width as f64;
+                    let v = (y as f64 + (sy as f64 + 0.5) / split as f64) / height as f64;
+                    let (lat, lon) = (FRAC_PI_2 - v * PI, u * 2.0 * PI - PI);
+                    let tile_id = octo.tile_at(lat, lon);
+                    sum[tile_id] += lat.cos() * samples[y * width + x];
+                    weight[tile_id] += lat.cos();
+                }
+            }
+        }
+    }
+    (0..octo.tile_count())
+        .map(|t| match weight[t] > 0.0 {
+            true => sum[t] / weight[t],
+            false => {
+                let (lat, lon) = octo.lat_lon(t);
+                let x = (((lon + PI) / (2.0 * PI) * width as f64) as usize).min(width - 1);
+                let y = (((FRAC_PI_2 - lat) / PI * height as f64) as usize).min(height - 1);
+                samples[y * width + x]
+            }
+        })
+        .collect()
+}
\ No newline at end of file
@@ -0,0 +1,108 @@
+#[cfg(test)]
+use crate::{
+    octo::Octo,
+    raster::{
+        adler32,
+        grayscale,
+        import_equirectangular,
+        MapProjection,
+        Raster,
+        PNG_SIGNATURE
+    },
+    world::crc32
+};
+#[cfg(test)]
+fn tile_colour(tile_id: usize) -> [u8; 3] {
+    [(tile_id >> 16) as u8, (tile_id >> 8) as u8, tile_id as u8]
+}
+#[test]
+fn test_render_matches_lookup() -> Result <(),String> {
+    let octo = Octo::new(6);
+    let raster = Raster::render(&octo, 64, 32, MapProjection::Equirectangular, tile_colour);
+    for (y, x) in [(0, 0), (5, 17), (16, 32), (31, 63)] {
+        let (lat, lon) = MapProjection::Equirectangular
+            .to_lat_lon((x as f64 + 0.5) / 64.0, (y as f64 + 0.5) / 32.0)
+            .ok_or("equirectangular covers the image")?;
+        assert_eq!(raster.get(x, y), tile_colour(octo.tile_at(lat, lon)));
+    }
+    Ok(())
+}
+#[test]
+fn test_mollweide_outline() -> Result <(),String> {
+    let projection = MapProjection::Mollweide;
+    assert_eq!(projection.to_lat_lon(0.01, 0.01), None);
+    assert_eq!(projection.to_lat_lon(0.5, 0.5), Some((0.0, 0.0)));
+    let (lat, lon) = projection.to_lat_lon(0.999, 0.5).ok_or("edge of the equator")?;
+    assert!(lat.abs() < 1e-9 && lon > 3.1);
+    let (lat, _) = projection.to_lat_lon(0.5, 0.0).ok_or("north pole")?;
+    assert!((lat - std::f64::consts::FRAC_PI_2).abs() < 1e-9);
+    let octo = Octo::new(4);
+    let raster = Raster::render(&octo, 40, 20, projection, |_| [255; 3]);
+    assert_eq!(raster.get(0, 0), [0; 3]);
+    assert_eq!(raster.get(20, 10), [255; 3]);
+    Ok(())
+}
+#[test]
+fn test_ppm_round_trip() -> Result <(),String> {
+    let octo = Octo::new(3);
+    let values: Vec<f64> = (0..octo.tile_count()).map(|t| t as f64).collect();
+    let raster = Raster::render(&octo, 12, 6, MapProjection::Equirectangular, grayscale(&values));
+    let mut bytes = Vec::new();
+    raster.write_ppm(&mut bytes).map_err(|e| e.to_string())?;
+    assert_eq!(Raster::read_ppm(&mut &bytes[..]).map_err(|e| e.to_string())?, raster);
+    let commented = b"P6\n# made elsewhere\n1 1 15\n\x0f\x00\x05";
+    assert_eq!(Raster::read_ppm(&mut &commented[..]).map_err(|e| e.to_string())?.get(0, 0), [255, 0, 85]);
+    assert!(Raster::read_ppm(&mut &b"P3\n1 1 255\n0 0 0"[..]).is_err());
+    let huge = Raster::read_ppm(&mut &b"P6\n4294967296 4294967296 255\n"[..]).map_err(|e| e.kind());
+    assert_eq!(huge, Err(std::io::ErrorKind::InvalidData));
+    assert!(Raster::read_ppm(&mut &b"P6\n2 2 255\n\0\0\0"[..]).is_err());
+    Ok(())
+}
+#[test]
+fn test_png_layout() -> Result <(),String> {
+    let mut raster = Raster::new(3, 2);
+    raster.set(2, 1, [1, 2, 3]);
+    let mut bytes = Vec::new();
+    raster.write_png(&mut bytes).map_err(|e| e.to_string())?;
+    assert_eq!(bytes[..8], PNG_SIGNATURE);
+    let mut chunks = Vec::new();
+    let mut at = 8;
+    while at < bytes.len() {
+        let length = u32::from_be_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]]) as usize;
+        let body = &bytes[at + 4..at + 8 + length];
+        let crc = &bytes[at + 8 + length..at + 12 + length];
+        assert_eq!(crc32(body).to_be_bytes(), crc);
+        chunks.push(body.to_vec());
+        at += 12 + length;
+    }
+    assert_eq!(chunks.len(), 3);
+    let data = &chunks[1][4..];
+    let mut scanlines = [0; 20];
+    scanlines[17..].copy_from_slice(&[1, 2, 3]);
+    assert_eq!(data[..2], [0x78, 0x01]);
+    assert_eq!(data[2..7], [1, 20, 0, !20, 0xFF]);
+    assert_eq!(data[7..27], scanlines[..]);
+    assert_eq!(data[27..], adler32(&data[7..27]).to_be_bytes());
+    Ok(())
+}
+#[test]
+fn test_import_averages() -> Result <(),String> {
+    let octo = Octo::new(8);
+    let constant = import_equirectangular(&octo, 36, &[2.5; 36 * 18]);
+    assert!(constant.iter().all(|v| (v - 2.5).abs() < 1e-12));
+    let (width, height) = (360, 180);
+    let latitude: Vec<f64> = (0..width * height)
+        .map(|i| 90.0 - ((i / width) as f64 + 0.5) * 180.0 / height as f64)
+        .collect();
+    let imported = import_equirectangular(&octo, width, &latitude);
+    for (t, value) in imported.iter().enumerate() {
+        let lat = octo.lat_lon(t).0.to_degrees();
+        assert!((value - lat).abs() < 8.0, "tile {} at {} imported {}", t, lat, value);
+    }
+    Ok(())
+}
+#[test]
+#[should_panic(expected = "whole rows of samples")]
+fn test_import_rejects_partial_rows() {
+    import_equirectangular(&Octo::new(2), 4, &[0.0; 10]);
+}
\ No newline at end of file
@@ -0,0 +1,157 @@
+#[cfg(test)]
+#[path = "./region_tests.rs"]
+mod region_tests;
+use std::collections::HashMap;
+use crate::{
+    automaton::Neighbourhood,
+    components::Components,
+    octo::Octo,
+    sphere::{
+        arc,
+        cross,
+        dot,
+        Vec3,
+        VertexKey
+    }
+};
+// A set of tiles, which may span several faces or a pole.
+#[derive(Clone,Debug,PartialEq)]
+pub struct Region {
+    tiles: Vec<usize>
+}
+impl Region {
+    pub fn new<I: IntoIterator<Item = usize>>(tiles: I) -> Region {
+        let mut tiles: Vec<usize> = tiles.into_iter().collect();
+        tiles.sort_unstable();
+        tiles.dedup();
+        Region {
+            tiles
+        }
+    }
+    pub fn tiles(&self) -> &[usize] {
+        &self.tiles
+    }
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+    pub fn contains(&self, tile_id: usize) -> bool {
+        self.tiles.binary_search(&tile_id).is_ok()
+    }
+    // Tiles in the region with an edge neighbour outside it.
+    pub fn boundary_tiles(&self, octo: &Octo) -> Vec<usize> {
+        self.tiles.iter()
+            .cloned()
+            .filter(|t| octo.neighbours(*t).iter().any(|n| !self.contains(*n)))
+            .collect()
+    }
+    // Edges with the region on their left when seen from outside the sphere.
+    fn boundary_edges(&self, octo: &Octo) -> Vec<(VertexKey, VertexKey)> {
+        let mut edges = Vec::new();
+        for &t in self.tiles.iter() {
+            let mut keys = octo.vertex_keys(t);
+            let [a, b, c] = octo.vertices(t);
+            let ab = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+            let ac = [c[0] - a[0], c[1] - a[1], c[2] - a[2]];
+            if dot(a, cross(ab, ac)) < 0.0 {
+                keys.swap(1, 2);
+            }
+            for &n in octo.neighbours(t).iter().filter(|n| !self.contains(**n)) {
+                let other = octo.vertex_keys(n);
+                for k in 0..3 {
+                    let (from, to) = (keys[k], keys[(k + 1) % 3]);
+                    if other.contains(&from) && other.contains(&to) {
+                        edges.push((from, to));
+                    }
+                }
+            }
+        }
+        edges
+    }
+    // Closed loops of boundary vertices, each listed once in order with the
+    // region to the left. Where pieces of the region touch at a single vertex
+    // each loop takes the sharpest left turn, so the loops split there rather
+    // than joining into a figure of eight. A region covering the whole sphere
+    // has none.
+    pub fn boundary_loops(&self, octo: &Octo) -> Vec<Vec<VertexKey>> {
+        let mut outgoing: HashMap<VertexKey, Vec<VertexKey>> = HashMap::new();
+        let edges = self.boundary_edges(octo);
+        for (from, to) in edges.iter() {
+            outgoing.entry(*from).or_default().push(*to);
+        }
+        let mut loops = Vec::new();
+        for &(start, first) in edges.iter() {
+            match outgoing.get_mut(&start).and_then(|o| o.iter().position(|k| *k == first)) {
+                Some(i) => outgoing.get_mut(&start).unwrap().swap_remove(i),
+                None => continue
+            };
+            let mut path = vec![start];
+            let (mut previous, mut current) = (start, first);
+            loop {
+                let mut options = outgoing.get(&current).cloned().unwrap_or_default();
+                if current == start {
+                    options.push(first);
+                }
+                let next = options.into_iter().min_by(|a, b| {
+                    left_turn(octo, previous, current, *a).total_cmp(&left_turn(octo, previous, current, *b))
+                });
+                match next {
+                    Some(next) if current != start || next != first => {
+                        let out = outgoing.get_mut(&current).unwrap();
+                        let i = out.iter().position(|k| *k == next).unwrap();
+                        out.swap_remove(i);
+                        path.push(current);
+                        previous = current;
+                        current = next;
+                    },
+                    _ => break
+                }
+            }
+            loops.push(path);
+        }
+        loops
+    }
+    pub fn boundary_loop_points(&self, octo: &Octo) -> Vec<Vec<Vec3>> {
+        self.boundary_loops(octo).iter()
+            .map(|l| l.iter().map(|k| octo.vertex_position(*k)).collect())
+            .collect()
+    }
+    // Solid angle covered, in steradians.
+    pub fn area(&self, octo: &Octo) -> f64 {
+        self.tiles.iter().map(|t| octo.tile_area(*t)).sum()
+    }
+    // Total great circle length of the boundary edges, in radians.
+    pub fn perimeter(&self, octo: &Octo) -> f64 {
+        self.boundary_edges(octo).iter()
+            .map(|(a, b)| arc(octo.vertex_position(*a), octo.vertex_position(*b)))
+            .sum()
+    }
+    pub fn component_count(&self, octo: &Octo) -> usize {
+        Components::label_where(octo, Neighbourhood::Edge, |t| self.contains(t)).len()
+    }
+    // Every edge connected piece of the rest of the sphere beyond the first
+    // is a hole, including pieces closed off at a pinch vertex.
+    pub fn hole_count(&self, octo: &Octo) -> usize {
+        Components::label_where(octo, Neighbourhood::Edge, |t| !self.contains(t)).len().saturating_sub(1)
+    }
+    pub fn has_holes(&self, octo: &Octo) -> bool {
+        self.hole_count(octo) > 0
+    }
+}
+// Clockwise angle seen from outside the sphere, from the edge back to
+// `previous` round to the edge out to `next`. The smallest keeps to the piece
+// of region on the left of the edge arriving at `current`.
+fn left_turn(octo: &Octo, previous: VertexKey, current: VertexKey, next: VertexKey) -> f64 {
+    let centre = octo.vertex_position(current);
+    let back = octo.vertex_position(previous);
+    let out = octo.vertex_position(next);
+    let a = [back[0] - centre[0], back[1] - centre[1], back[2] - centre[2]];
+    let b = [out[0] - centre[0], out[1] - centre[1], out[2] - centre[2]];
+    let angle = (-dot(centre, cross(a, b))).atan2(dot(a, b));
+    match angle > 0.0 {
+        true => angle,
+        false => angle + 2.0 * std::f64::consts::PI
+    }
+}
\ No newline at end of file
@@ -0,0 +1,115 @@
+#[cfg(test)]
+use crate::{
+    automaton::Neighbourhood,
+    components::Components,
+    octo::Octo,
+    region::Region,
+    sphere::{
+        arc,
+        dot
+    }
+};
+#[test]
+fn test_vertex_keys_match_positions() -> Result <(),String> {
+    for size in 1..6 {
+        let octo = Octo::new(size);
+        for t in 0..octo.tile_count() {
+            let keys = octo.vertex_keys(t);
+            let vertices = octo.vertices(t);
+            for k in 0..3 {
+                assert!(dot(octo.vertex_position(keys[k]), vertices[k]) > 1.0 - 1e-12);
+                assert_eq!(keys[k].iter().map(|c| c.abs()).sum::<i64>(), size as i64);
+            }
+        }
+    }
+    Ok(())
+}
+#[test]
+fn test_single_tile_region() -> Result <(),String> {
+    let octo = Octo::new(3);
+    let region = Region::new(vec![40]);
+    let loops = region.boundary_loops(&octo);
+    assert_eq!(loops.len(), 1);
+    assert_eq!(loops[0].len(), 3);
+    let [a, b, c] = octo.vertices(40);
+    assert!((region.perimeter(&octo) - (arc(a, b) + arc(b, c) + arc(c, a))).abs() < 1e-12);
+    assert_eq!(region.area(&octo), octo.tile_area(40));
+    assert_eq!(region.boundary_tiles(&octo), vec![40]);
+    assert!(!region.has_holes(&octo));
+    Ok(())
+}
+#[test]
+fn test_polar_cap_and_band() -> Result <(),String> {
+    let octo = Octo::new(8);
+    let cap = Region::new((0..octo.tile_count()).filter(|t| octo.lat_lon(*t).0 > 0.9));
+    assert!(cap.tiles().iter().any(|t| t / 64 == 0) && cap.tiles().iter().any(|t| t / 64 == 2));
+    let loops = cap.boundary_loops(&octo);
+    assert_eq!(loops.len(), 1);
+    assert_eq!(cap.hole_count(&octo), 0);
+    let points = cap.boundary_loop_points(&octo);
+    let length: f64 = (0..points[0].len()).map(|i| arc(points[0][i], points[0][(i + 1) % points[0].len()])).sum();
+    assert!((length - cap.perimeter(&octo)).abs() < 1e-9);
+    let band = Region::new((0..octo.tile_count()).filter(|t| octo.lat_lon(*t).0.abs() < 0.3));
+    assert_eq!(band.boundary_loops(&octo).len(), 2);
+    assert!(band.has_holes(&octo));
+    let whole = Region::new(0..octo.tile_count());
+    assert!(whole.boundary_loops(&octo).is_empty());
+    assert!((whole.area(&octo) - 4.0 * std::f64::consts::PI).abs() < 1e-9);
+    assert_eq!(whole.perimeter(&octo), 0.0);
+    Ok(())
+}
+#[test]
+fn test_region_with_hole() -> Result <(),String> {
+    let octo = Octo::new(8);
+    let centre = octo.tile_at(0.5, 0.8);
+    let mut tiles: Vec<usize> = (0..octo.tile_count())
+        .filter(|t| arc(octo.centroid(*t), octo.centroid(centre)) < 0.5)
+        .collect();
+    tiles.retain(|t| *t != centre);
+    let region = Region::new(tiles);
+    assert_eq!(region.component_count(&octo), 1);
+    assert_eq!(region.hole_count(&octo), 1);
+    assert!(region.boundary_loops(&octo).iter().any(|l| l.len() == 3));
+    Ok(())
+}
+#[test]
+fn test_pinch_vertex_splits_loops() -> Result <(),String> {
+    let octo = Octo::new(4);
+    let tile = 21;
+    let neighbours = octo.neighbours(tile);
+    let corner = octo.vertex_neighbours(tile).into_iter()
+        .find(|n| !neighbours.contains(n))
+        .ok_or("a tile meeting only at a vertex")?;
+    let pinch = Region::new(vec![tile, corner]);
+    let loops = pinch.boundary_loops(&octo);
+    assert_eq!(loops.len(), 2);
+    assert!(loops.iter().all(|l| l.len() == 3));
+    assert_eq!(pinch.component_count(&octo), 2);
+    assert_eq!(pinch.hole_count(&octo), 0);
+    let ring = Region::new(octo.vertex_neighbours(tile).into_iter().filter(|n| *n != corner));
+    assert_eq!(ring.component_count(&octo), 1);
+    assert_eq!(ring.hole_count(&octo), 1);
+    Ok(())
+}
+#[test]
+fn test_loops_cover_boundary() -> Result <(),String> {
+    let octo = Octo::new(6);
+    let mut state: u64 = 7;
+    for _ in 0..200 {
+        let region = Region::new((0..octo.tile_count()).filter(|_| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 33) % 3 == 1
+        }));
+        let loops = region.boundary_loops(&octo);
+        let pieces = Components::label_where(&octo, Neighbourhood::Edge, |t| region.contains(t)).len();
+        let gaps = Components::label_where(&octo, Neighbourhood::Edge, |t| !region.contains(t)).len();
+        assert!(loops.len() >= pieces);
+        let edges: usize = loops.iter().map(|l| l.len()).sum();
+        let open = region.tiles().iter()
+            .map(|t| octo.neighbours(*t).iter().filter(|n| !region.contains(**n)).count())
+            .sum::<usize>();
+        assert_eq!(edges, open);
+        assert_eq!(region.hole_count(&octo), gaps.saturating_sub(1));
+    }
+    Ok(())
+}
\ No newline at end of file
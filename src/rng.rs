@@ -0,0 +1,40 @@
+// SplitMix64, small and seedable so generated worlds are reproducible
+// without pulling in a random number crate.
+#[derive(Clone,Debug,PartialEq)]
+pub struct Rng {
+    state: u64
+}
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng {
+            state: seed
+        }
+    }
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+    // Uniform in [0, 1).
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+    // Uniform in 0..bound, bound must be positive.
+    pub fn below(&mut self, bound: usize) -> usize {
+        ((self.next_u64() as u128 * bound as u128) >> 64) as usize
+    }
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+    // Uniform direction on the unit sphere.
+    pub fn unit_vector(&mut self) -> [f64; 3] {
+        let z = 2.0 * self.next_f64() - 1.0;
+        let a = 2.0 * std::f64::consts::PI * self.next_f64();
+        let r = (1.0 - z * z).sqrt();
+        [r * a.cos(), r * a.sin(), z]
+    }
+}
\ No newline at end of file
@@ -0,0 +1,79 @@
+#[cfg(test)]
+#[path = "./sampling_tests.rs"]
+mod sampling_tests;
+use crate::{
+    automaton::Neighbourhood,
+    components::flood_fill_with,
+    octo::Octo,
+    rng::Rng,
+    sphere::arc
+};
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum Separation {
+    // Minimum number of edge steps between any two samples.
+    Steps(usize),
+    // Minimum angle between the centroids of any two samples.
+    Radians(f64)
+}
+// A tile picked with probability proportional to its spherical area, by
+// looking up a uniformly random point on the sphere.
+pub fn uniform_tile(octo: &Octo, rng: &mut Rng) -> usize {
+    octo.tile_at_point(rng.unit_vector())
+}
+pub fn uniform_tiles(octo: &Octo, seed: u64, count: usize) -> Vec<usize> {
+    let mut rng = Rng::new(seed);
+    (0..count).map(|_| uniform_tile(octo, &mut rng)).collect()
+}
+// Dart throwing with area weighted darts. Each accepted sample blocks every
+// tile closer than the separation, and sampling stops once attempts darts in
+// a row have landed on blocked tiles.
+pub fn poisson_disc(octo: &Octo, seed: u64, separation: Separation, attempts: usize) -> Vec<usize> {
+    let mut rng = Rng::new(seed);
+    let mut blocked = vec![false; octo.tile_count()];
+    // Scratch shared by every sample, only the tiles reached are reset.
+    let mut distance = vec![usize::MAX; octo.tile_count()];
+    let mut seen = vec![false; octo.tile_count()];
+    let mut samples = Vec::new();
+    let mut misses = 0;
+    while misses < attempts {
+        let tile_id = uniform_tile(octo, &mut rng);
+        if blocked[tile_id] {
+            misses += 1;
+            continue;
+        }
+        misses = 0;
+        samples.push(tile_id);
+        let near = match separation {
+            Separation::Steps(steps) => {
+                distance[tile_id] = 0;
+                let mut near = vec![tile_id];
+                let mut next = 0;
+                while next < near.len() {
+                    let t = near[next];
+                    if distance[t] + 1 < steps {
+                        for &n in octo.neighbours(t).iter() {
+                            if distance[n] == usize::MAX {
+                                distance[n] = distance[t] + 1;
+                                near.push(n);
+                            }
+                        }
+                    }
+                    next += 1;
+                }
+                for t in near.iter() {
+                    distance[*t] = usize::MAX;
+                }
+                near
+            },
+            Separation::Radians(radians) => {
+                let centre = octo.centroid(tile_id);
+                flood_fill_with(octo, tile_id, Neighbourhood::Vertex, &mut seen, |t| arc(octo.centroid(t), centre) < radians)
+            }
+        };
+        for t in near {
+            blocked[t] = true;
+        }
+        blocked[tile_id] = true;
+    }
+    samples
+}
\ No newline at end of file
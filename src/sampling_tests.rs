@@ -0,0 +1,59 @@
+#[cfg(test)]
+use crate::{
+    octo::Octo,
+    sampling::{
+        poisson_disc,
+        uniform_tiles,
+        Separation
+    },
+    sphere::arc
+};
+#[test]
+fn test_uniform_tiles_follow_area() -> Result <(),String> {
+    let octo = Octo::new(3);
+    let samples = uniform_tiles(&octo, 1, 400_000);
+    assert_eq!(samples, uniform_tiles(&octo, 1, 400_000));
+    let mut counts = vec![0usize; octo.tile_count()];
+    for s in samples.iter() {
+        counts[*s] += 1;
+    }
+    let total: f64 = (0..octo.tile_count()).map(|t| octo.tile_area(t)).sum();
+    for (t, count) in counts.iter().enumerate() {
+        let expected = octo.tile_area(t) / total * samples.len() as f64;
+        assert!((*count as f64 - expected).abs() < 5.0 * expected.sqrt(), "tile {} {} {}", t, count, expected);
+    }
+    Ok(())
+}
+#[test]
+fn test_poisson_disc_steps() -> Result <(),String> {
+    let octo = Octo::new(12);
+    let samples = poisson_disc(&octo, 3, Separation::Steps(4), 500);
+    assert_eq!(samples, poisson_disc(&octo, 3, Separation::Steps(4), 500));
+    assert!(samples.len() > 10);
+    for s in samples.iter() {
+        let mut frontier = vec![*s];
+        let mut seen = vec![*s];
+        for _ in 1..4 {
+            frontier = frontier.iter().flat_map(|t| octo.neighbours(*t).to_vec()).filter(|t| !seen.contains(t)).collect();
+            seen.extend(frontier.iter().cloned());
+        }
+        assert!(samples.iter().all(|o| o == s || !seen.contains(o)));
+    }
+    Ok(())
+}
+#[test]
+fn test_poisson_disc_radians() -> Result <(),String> {
+    let octo = Octo::new(16);
+    let samples = poisson_disc(&octo, 8, Separation::Radians(0.4), 1000);
+    assert!(samples.len() > 20);
+    for (i, a) in samples.iter().enumerate() {
+        for b in samples[i + 1..].iter() {
+            assert!(arc(octo.centroid(*a), octo.centroid(*b)) >= 0.4);
+        }
+    }
+    let coverage = (0..octo.tile_count())
+        .filter(|t| samples.iter().any(|s| arc(octo.centroid(*s), octo.centroid(*t)) < 0.4))
+        .count();
+    assert!(coverage as f64 > 0.95 * octo.tile_count() as f64);
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,194 @@
+// Mapping of tiles onto the unit sphere.
+// Upper face f spans longitudes f to f + 1 quarter turns with its index 0 at
+// the north pole, lower face 7 - f sits below it with index 0 at the south
+// pole. Positions inside a face are barycentric weights of the pole and the
+// two equatorial corners, carried onto the sphere by the octahedron's
+// projection. Angles are in radians.
+#[cfg(test)]
+#[path = "./sphere_tests.rs"]
+mod sphere_tests;
+use crate::{
+    octo::{
+        isqrt,
+        Octo
+    },
+    tile::{
+        Direction,
+        TileType
+    }
+};
+pub type Vec3 = [f64; 3];
+// A lattice vertex as face_size times its point on the flat octahedron
+// |x| + |y| + |z| = 1, which is exact and shared by every face touching it.
+pub type VertexKey = [i64; 3];
+pub(crate) fn dot(a: Vec3, b: Vec3) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+pub(crate) fn cross(a: Vec3, b: Vec3) -> Vec3 {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0]
+    ]
+}
+pub(crate) fn normalize(a: Vec3) -> Vec3 {
+    let l = dot(a, a).sqrt();
+    [a[0] / l, a[1] / l, a[2] / l]
+}
+pub fn to_lat_lon(p: Vec3) -> (f64, f64) {
+    (p[2].clamp(-1.0, 1.0).asin(), p[1].atan2(p[0]))
+}
+pub fn from_lat_lon(lat: f64, lon: f64) -> Vec3 {
+    [lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin()]
+}
+// The pole, west and east corners of a face.
+pub(crate) fn face_corners(face_id: usize) -> [Vec3; 3] {
+    let equator = |k: usize| match k % 4 {
+        0 => [1.0, 0.0, 0.0],
+        1 => [0.0, 1.0, 0.0],
+        2 => [-1.0, 0.0, 0.0],
+        _ => [0.0, -1.0, 0.0]
+    };
+    match face_id {
+        _ if face_id > 3 => [[0.0, 0.0, -1.0], equator(8 - face_id), equator(7 - face_id)],
+        _ => [[0.0, 0.0, 1.0], equator(face_id), equator(face_id + 1)]
+    }
+}
+pub(crate) fn face_of(p: Vec3) -> usize {
+    let upper = match (p[0] >= 0.0, p[1] >= 0.0) {
+        (true, true) => 0,
+        (false, true) => 1,
+        (false, false) => 2,
+        (true, false) => 3
+    };
+    match p[2] < 0.0 {
+        true => 7 - upper,
+        false => upper
+    }
+}
+// Weights of a point on the flat octahedron within the given face.
+pub(crate) fn planar_weights(face_id: usize, p: Vec3) -> Vec3 {
+    let [a, b, c] = face_corners(face_id);
+    let w = [dot(p, a).max(0.0), dot(p, b).max(0.0), dot(p, c).max(0.0)];
+    let sum = w[0] + w[1] + w[2];
+    [w[0] / sum, w[1] / sum, w[2] / sum]
+}
+impl Octo {
+    pub(crate) fn face_point(&self, face_id: usize, weights: Vec3) -> Vec3 {
+        self.projection().to_sphere(face_corners(face_id), weights)
+    }
+    // Barycentric weights of the three corners of a tile within its face.
+    pub fn tile_weights(&self, tile_id: usize) -> [Vec3; 3] {
+        let n = self.face_size();
+        let index_id = tile_id % usize::pow(n, 2);
+        let h = isqrt(index_id);
+        let k = (index_id - usize::pow(h, 2)) / 2;
+        let lattice = match self.tile_type(tile_id) {
+            TileType::Point => [(h, k), (h + 1, k), (h + 1, k + 1)],
+            TileType::Flat => [(h, k), (h, k + 1), (h + 1, k + 1)]
+        };
+        let n = n as f64;
+        let weight = |(r, c): (usize, usize)| [1.0 - r as f64 / n, (r - c) as f64 / n, c as f64 / n];
+        [weight(lattice[0]), weight(lattice[1]), weight(lattice[2])]
+    }
+    pub fn vertex_keys(&self, tile_id: usize) -> [VertexKey; 3] {
+        let n = self.face_size() as f64;
+        let corners = face_corners(tile_id / usize::pow(self.face_size(), 2));
+        let key = |w: Vec3| {
+            let mut k = [0; 3];
+            for (axis, value) in k.iter_mut().enumerate() {
+                *value = (n * (w[0] * corners[0][axis] + w[1] * corners[1][axis] + w[2] * corners[2][axis])).round() as i64;
+            }
+            k
+        };
+        let [a, b, c] = self.tile_weights(tile_id);
+        [key(a), key(b), key(c)]
+    }
+    pub fn vertex_position(&self, key: VertexKey) -> Vec3 {
+        let p = [key[0] as f64, key[1] as f64, key[2] as f64];
+        let face_id = face_of(p);
+        self.face_point(face_id, planar_weights(face_id, p))
+    }
+    pub fn vertices(&self, tile_id: usize) -> [Vec3; 3] {
+        let face_id = tile_id / usize::pow(self.face_size(), 2);
+        let [a, b, c] = self.tile_weights(tile_id);
+        [self.face_point(face_id, a), self.face_point(face_id, b), self.face_point(face_id, c)]
+    }
+    pub fn centroid(&self, tile_id: usize) -> Vec3 {
+        let face_id = tile_id / usize::pow(self.face_size(), 2);
+        let [a, b, c] = self.tile_weights(tile_id);
+        self.face_point(face_id, [
+            (a[0] + b[0] + c[0]) / 3.0,
+            (a[1] + b[1] + c[1]) / 3.0,
+            (a[2] + b[2] + c[2]) / 3.0
+        ])
+    }
+    // Solid angle of the spherical triangle, in steradians.
+    pub fn tile_area(&self, tile_id: usize) -> f64 {
+        let [a, b, c] = self.vertices(tile_id);
+        2.0 * dot(a, cross(b, c)).abs().atan2(1.0 + dot(a, b) + dot(b, c) + dot(c, a))
+    }
+    // Great circle lengths of the edges a-b, b-c and c-a of the vertices.
+    pub fn edge_lengths(&self, tile_id: usize) -> [f64; 3] {
+        let [a, b, c] = self.vertices(tile_id);
+        [arc(a, b), arc(b, c), arc(c, a)]
+    }
+    // Interior angle at each vertex, their excess over pi is the tile area.
+    pub fn interior_angles(&self, tile_id: usize) -> [f64; 3] {
+        let [a, b, c] = self.vertices(tile_id);
+        let angle = |p: Vec3, q: Vec3, r: Vec3| arc(normalize(cross(p, q)), normalize(cross(p, r)));
+        [angle(a, b, c), angle(b, c, a), angle(c, a, b)]
+    }
+    pub fn lat_lon(&self, tile_id: usize) -> (f64, f64) {
+        to_lat_lon(self.centroid(tile_id))
+    }
+    pub fn tile_at(&self, lat: f64, lon: f64) -> usize {
+        self.tile_at_point(from_lat_lon(lat, lon))
+    }
+    pub fn tile_at_point(&self, p: Vec3) -> usize {
+        let face_id = face_of(p);
+        self.tile_at_weights(face_id, self.projection().to_weights(face_corners(face_id), p))
+    }
+    // The tile of a face holding the point with the given barycentric weights.
+    pub(crate) fn tile_at_weights(&self, face_id: usize, w: Vec3) -> usize {
+        let n = self.face_size();
+        let (mut u, mut v) = (w[1] * n as f64, w[2] * n as f64);
+        if u + v >= n as f64 {
+            let scale = (n as f64 - 1e-9) / (u + v);
+            u *= scale;
+            v *= scale;
+        }
+        let (i, j) = (u.floor() as usize, v.floor() as usize);
+        let (h, position) = match u - i as f64 + v - j as f64 {
+            f if f < 1.0 => (i + j, 2 * j),
+            _ => (i + j + 1, 2 * j + 1)
+        };
+        face_id * usize::pow(n, 2) + usize::pow(h, 2) + position
+    }
+    // Unit tangent at the tile centroid pointing at the tile reached by
+    // stepping in direction, so it already accounts for face changes.
+    pub fn tangent(&self, tile_id: usize, direction: &Direction) -> Vec3 {
+        let p = self.centroid(tile_id);
+        let q = self.centroid(self.step((tile_id, direction.clone())).0);
+        let along = dot(p, q);
+        normalize([q[0] - along * p[0], q[1] - along * p[1], q[2] - along * p[2]])
+    }
+    // The step direction whose tangent best matches a vector at the tile.
+    pub fn direction_toward(&self, tile_id: usize, v: Vec3) -> Direction {
+        (0..6).map(Direction::from_index)
+            .max_by(|a, b| dot(self.tangent(tile_id, a), v).total_cmp(&dot(self.tangent(tile_id, b), v)))
+            .unwrap_or(Direction::PosX)
+    }
+}
+pub fn arc(a: Vec3, b: Vec3) -> f64 {
+    dot(a, b).clamp(-1.0, 1.0).acos()
+}
+pub fn east(p: Vec3) -> Vec3 {
+    match p[0] * p[0] + p[1] * p[1] {
+        r if r > 0.0 => normalize([-p[1], p[0], 0.0]),
+        _ => [0.0, 1.0, 0.0]
+    }
+}
+pub fn north(p: Vec3) -> Vec3 {
+    cross(p, east(p))
+}
\ No newline at end of file
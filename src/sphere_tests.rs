@@ -0,0 +1,110 @@
+#[cfg(test)]
+use crate::{
+    octo::Octo,
+    sphere::{
+        cross,
+        dot,
+        from_lat_lon,
+        north,
+        to_lat_lon
+    },
+    tile::Direction
+};
+#[test]
+fn test_neighbours_share_an_edge() -> Result <(),String> {
+    for size in 1..7 {
+        let octo = Octo::new(size);
+        for i in 0..octo.tile_count() {
+            let vertices = octo.vertices(i);
+            for n in octo.neighbours(i).iter() {
+                let shared = octo.vertices(*n).iter()
+                    .filter(|v| vertices.iter().any(|w| dot(**v, *w) > 1.0 - 1e-9))
+                    .count();
+                assert_eq!(shared, 2, "{} and {} in size {}", i, n, size);
+            }
+        }
+    }
+    Ok(())
+}
+#[test]
+fn test_centroid_lookup_round_trip() -> Result <(),String> {
+    for size in 1..10 {
+        let octo = Octo::new(size);
+        for i in 0..octo.tile_count() {
+            let (lat, lon) = octo.lat_lon(i);
+            assert_eq!(octo.tile_at(lat, lon), i);
+            assert!((dot(octo.centroid(i), octo.centroid(i)) - 1.0).abs() < 1e-12);
+        }
+    }
+    Ok(())
+}
+#[test]
+fn test_lat_lon_layout() -> Result <(),String> {
+    let octo = Octo::new(4);
+    assert!(octo.lat_lon(0).0 > 1.2);
+    assert!(octo.lat_lon(7 * 16).0 < -1.2);
+    assert_eq!(octo.tile_at(0.1, 0.1) / 16, 0);
+    assert_eq!(octo.tile_at(0.1, 1.7) / 16, 1);
+    assert_eq!(octo.tile_at(-0.1, 0.1) / 16, 7);
+    assert_eq!(octo.tile_at(-0.1, -0.1) / 16, 4);
+    assert_eq!(octo.tile_at(std::f64::consts::FRAC_PI_2, 0.0), 0);
+    let (lat, lon) = to_lat_lon(from_lat_lon(0.3, -2.0));
+    assert!((lat - 0.3).abs() < 1e-12 && (lon + 2.0).abs() < 1e-12);
+    Ok(())
+}#[test]
+fn test_tangents_point_at_steps() -> Result <(),String> {
+    for size in 1..7 {
+        let octo = Octo::new(size);
+        for i in 0..octo.tile_count() {
+            for d in 0..6 {
+                let direction = Direction::from_index(d);
+                let t = octo.tangent(i, &direction);
+                assert!(dot(t, octo.centroid(i)).abs() < 1e-9);
+                assert_eq!(octo.direction_toward(i, t), direction, "{} {:?} size {}", i, direction, size);
+            }
+        }
+    }
+    let octo = Octo::new(8);
+    let tile = octo.tile_at(0.4, 0.3);
+    assert!(dot(octo.tangent(tile, &Direction::PosX), north(octo.centroid(tile))) > 0.5);
+    let tile = octo.tile_at(-0.4, 2.3);
+    assert!(dot(octo.tangent(tile, &Direction::PosX), north(octo.centroid(tile))) > 0.5);
+    Ok(())
+}#[test]
+fn test_vertex_neighbours_share_a_corner() -> Result <(),String> {
+    for size in 1..6 {
+        let octo = Octo::new(size);
+        for i in 0..octo.tile_count() {
+            let vertices = octo.vertices(i);
+            let expected: Vec<usize> = (0..octo.tile_count())
+                .filter(|j| *j != i && octo.vertices(*j).iter().any(|v| vertices.iter().any(|w| dot(*v, *w) > 1.0 - 1e-9)))
+                .collect();
+            assert_eq!(octo.vertex_neighbours(i), expected, "{} in size {}", i, size);
+        }
+    }
+    assert_eq!(Octo::new(5).vertex_neighbours(12).len(), 12);
+    let mut buffer = vec![7; 20];
+    Octo::new(5).vertex_neighbours_into(12, &mut buffer);
+    assert_eq!(buffer, Octo::new(5).vertex_neighbours(12));
+    Ok(())
+}#[test]
+fn test_tile_areas_cover_the_sphere() -> Result <(),String> {
+    for size in 1..9 {
+        let octo = Octo::new(size);
+        let total: f64 = (0..octo.tile_count()).map(|i| octo.tile_area(i)).sum();
+        assert!((total - 4.0 * std::f64::consts::PI).abs() < 1e-9, "size {}", size);
+    }
+    Ok(())
+}
+#[test]
+fn test_directions_run_clockwise() -> Result <(),String> {
+    let octo = Octo::new(6);
+    for t in 0..octo.tile_count() {
+        let p = octo.centroid(t);
+        for d in Direction::all() {
+            let (a, b) = (octo.tangent(t, &d), octo.tangent(t, &d.rotate_cw()));
+            assert!(dot(cross(a, b), p) < 0.0, "{:?} to {:?} turns anticlockwise at {}", d, d.rotate_cw(), t);
+        }
+    }
+    Ok(())
+}
\ No newline at end of file
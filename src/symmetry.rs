@@ -0,0 +1,157 @@
+// The 48 symmetries of the octahedron acting on tiles and step vectors.
+// A symmetry is a signed permutation of the axes, so it maps vertex keys to
+// vertex keys exactly and tiles to tiles. Directions follow the tile they
+// step to: the image of a direction is the one stepping from the image tile
+// to the image of the original destination.
+#[cfg(test)]
+#[path = "./symmetry_tests.rs"]
+mod symmetry_tests;
+use crate::{
+    octo::Octo,
+    sphere::{
+        face_of,
+        planar_weights,
+        Vec3,
+        VertexKey
+    },
+    tile::Direction
+};
+// Sends axis i of a point to axis i of its image scaled by the sign, so
+// image[i] = signs[i] * p[axes[i]].
+#[derive(Clone,Copy,Debug,PartialEq,Eq,Hash)]
+pub struct Symmetry {
+    axes: [usize; 3],
+    signs: [i64; 3]
+}
+impl Symmetry {
+    pub fn identity() -> Symmetry {
+        Symmetry {
+            axes: [0, 1, 2],
+            signs: [1, 1, 1]
+        }
+    }
+    // Quarter turns eastward about the polar axis, carrying face f to f + 1.
+    pub fn rotate_polar(quarter_turns: usize) -> Symmetry {
+        let quarter = Symmetry {
+            axes: [1, 0, 2],
+            signs: [-1, 1, 1]
+        };
+        (0..quarter_turns % 4).fold(Symmetry::identity(), |s, _| quarter.compose(&s))
+    }
+    // Reflection through the equatorial plane.
+    pub fn swap_hemispheres() -> Symmetry {
+        Symmetry {
+            axes: [0, 1, 2],
+            signs: [1, 1, -1]
+        }
+    }
+    // Half turn about the x axis, swapping hemispheres without mirroring.
+    pub fn flip_poles() -> Symmetry {
+        Symmetry {
+            axes: [0, 1, 2],
+            signs: [1, -1, -1]
+        }
+    }
+    pub fn all() -> Vec<Symmetry> {
+        let permutations = [[0, 1, 2], [0, 2, 1], [1, 0, 2], [1, 2, 0], [2, 0, 1], [2, 1, 0]];
+        let mut symmetries = Vec::with_capacity(48);
+        for axes in permutations {
+            for bits in 0..8 {
+                let sign = |b: usize| match bits >> b & 1 {
+                    1 => -1,
+                    _ => 1
+                };
+                symmetries.push(Symmetry {
+                    axes,
+                    signs: [sign(0), sign(1), sign(2)]
+                });
+            }
+        }
+        symmetries
+    }
+    // Applies other first and then self.
+    pub fn compose(&self, other: &Symmetry) -> Symmetry {
+        let mut axes = [0; 3];
+        let mut signs = [0; 3];
+        for i in 0..3 {
+            axes[i] = other.axes[self.axes[i]];
+            signs[i] = self.signs[i] * other.signs[self.axes[i]];
+        }
+        Symmetry {
+            axes,
+            signs
+        }
+    }
+    pub fn inverse(&self) -> Symmetry {
+        let mut axes = [0; 3];
+        let mut signs = [0; 3];
+        for i in 0..3 {
+            axes[self.axes[i]] = i;
+            signs[self.axes[i]] = self.signs[i];
+        }
+        Symmetry {
+            axes,
+            signs
+        }
+    }
+    // True for the 24 that turn the sphere rather than mirror it.
+    pub fn is_rotation(&self) -> bool {
+        let inversions = (0..3)
+            .flat_map(|i| (i + 1..3).map(move |j| (i, j)))
+            .filter(|(i, j)| self.axes[*i] > self.axes[*j])
+            .count();
+        let parity = match inversions % 2 {
+            0 => 1,
+            _ => -1
+        };
+        parity * self.signs[0] * self.signs[1] * self.signs[2] == 1
+    }
+    pub fn apply_point(&self, p: Vec3) -> Vec3 {
+        [
+            self.signs[0] as f64 * p[self.axes[0]],
+            self.signs[1] as f64 * p[self.axes[1]],
+            self.signs[2] as f64 * p[self.axes[2]]
+        ]
+    }
+    pub fn apply_key(&self, key: VertexKey) -> VertexKey {
+        [
+            self.signs[0] * key[self.axes[0]],
+            self.signs[1] * key[self.axes[1]],
+            self.signs[2] * key[self.axes[2]]
+        ]
+    }
+}
+impl Octo {
+    pub fn apply_symmetry(&self, symmetry: &Symmetry, tile_id: usize) -> usize {
+        let keys = self.vertex_keys(tile_id).map(|k| symmetry.apply_key(k));
+        let mut p = [0.0; 3];
+        for (axis, value) in p.iter_mut().enumerate() {
+            *value = (keys[0][axis] + keys[1][axis] + keys[2][axis]) as f64;
+        }
+        let face_id = face_of(p);
+        self.tile_at_weights(face_id, planar_weights(face_id, p))
+    }
+    pub fn apply_symmetry_vector(&self, symmetry: &Symmetry, vector: (usize, Direction)) -> (usize, Direction) {
+        let image = self.apply_symmetry(symmetry, vector.0);
+        let target = self.apply_symmetry(symmetry, self.step(vector).0);
+        let direction = (0..6)
+            .map(Direction::from_index)
+            .find(|d| self.step((image, d.clone())).0 == target)
+            .expect("a symmetry maps every step onto a step");
+        (image, direction)
+    }
+    // The lowest tile equivalent to this one, and a symmetry taking it there.
+    pub fn canonical_tile(&self, tile_id: usize) -> (usize, Symmetry) {
+        Symmetry::all().into_iter()
+            .map(|s| (self.apply_symmetry(&s, tile_id), s))
+            .min_by_key(|(t, _)| *t)
+            .unwrap_or((tile_id, Symmetry::identity()))
+    }
+    // The lowest equivalent vector, ordered by tile then direction index.
+    pub fn canonical_vector(&self, vector: (usize, Direction)) -> ((usize, Direction), Symmetry) {
+        Symmetry::all().into_iter()
+            .map(|s| (self.apply_symmetry_vector(&s, vector.clone()), s))
+            .min_by_key(|((t, d), _)| (*t, d.index()))
+            .unwrap_or((vector, Symmetry::identity()))
+    }
+}
\ No newline at end of file
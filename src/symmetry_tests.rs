@@ -0,0 +1,94 @@
+#[cfg(test)]
+use crate::{
+    octo::Octo,
+    symmetry::Symmetry,
+    tile::Direction
+};
+#[test]
+fn test_group() -> Result <(),String> {
+    let all = Symmetry::all();
+    assert_eq!(all.len(), 48);
+    assert_eq!(all.iter().filter(|s| s.is_rotation()).count(), 24);
+    for a in all.iter() {
+        assert_eq!(a.compose(&a.inverse()), Symmetry::identity());
+        for b in all.iter() {
+            let ab = a.compose(b);
+            assert!(all.contains(&ab));
+            let p = [0.3, -0.5, 0.7];
+            assert_eq!(ab.apply_point(p), a.apply_point(b.apply_point(p)));
+        }
+    }
+    assert_eq!(Symmetry::rotate_polar(4), Symmetry::identity());
+    assert!(!Symmetry::swap_hemispheres().is_rotation());
+    assert!(Symmetry::flip_poles().is_rotation());
+    Ok(())
+}
+#[test]
+fn test_named_symmetries() -> Result <(),String> {
+    let octo = Octo::new(4);
+    let face_base = 16;
+    for t in 0..octo.tile_count() {
+        let face_id = t / face_base;
+        let rotated = octo.apply_symmetry(&Symmetry::rotate_polar(1), t);
+        let upper = match face_id > 3 {
+            true => 7 - face_id,
+            false => face_id
+        };
+        let expected = match face_id > 3 {
+            true => 7 - (upper + 1) % 4,
+            false => (upper + 1) % 4
+        };
+        assert_eq!(rotated / face_base, expected, "tile {}", t);
+        assert_eq!(rotated % face_base, t % face_base);
+        let swapped = octo.apply_symmetry(&Symmetry::swap_hemispheres(), t);
+        assert_eq!(swapped / face_base, 7 - face_id);
+        assert_eq!(octo.centroid(swapped)[2], -octo.centroid(t)[2]);
+    }
+    Ok(())
+}
+#[test]
+fn test_tiles_permuted() -> Result <(),String> {
+    let octo = Octo::new(5);
+    for s in Symmetry::all() {
+        let mut seen = vec![false; octo.tile_count()];
+        for t in 0..octo.tile_count() {
+            let image = octo.apply_symmetry(&s, t);
+            let p = s.apply_point(octo.centroid(t));
+            let q = octo.centroid(image);
+            assert!((0..3).all(|i| (p[i] - q[i]).abs() < 1e-9), "{:?} sends tile {} to {}", s, t, image);
+            seen[image] = true;
+        }
+        assert!(seen.iter().all(|s| *s));
+    }
+    Ok(())
+}
+#[test]
+fn test_step_equivariance() -> Result <(),String> {
+    for size in 1..6 {
+        let octo = Octo::new(size);
+        for s in Symmetry::all() {
+            for t in 0..octo.tile_count() {
+                for d in (0..6).map(Direction::from_index) {
+                    let stepped = octo.step(octo.apply_symmetry_vector(&s, (t, d.clone())));
+                    let expected = octo.apply_symmetry_vector(&s, octo.step((t, d.clone())));
+                    assert_eq!(stepped, expected, "size {} {:?} from ({}, {:?})", size, s, t, d);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+#[test]
+fn test_canonical() -> Result <(),String> {
+    let octo = Octo::new(6);
+    for t in 0..octo.tile_count() {
+        let (canonical, s) = octo.canonical_tile(t);
+        assert_eq!(octo.apply_symmetry(&s, t), canonical);
+        assert!(canonical < 36);
+        let image = octo.apply_symmetry(&Symmetry::rotate_polar(3), t);
+        assert_eq!(octo.canonical_tile(image).0, canonical);
+    }
+    let ((tile, _), _) = octo.canonical_vector((100, Direction::NegY));
+    assert_eq!(tile, octo.canonical_tile(100).0);
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,171 @@
+#[cfg(test)]
+#[path = "./tectonics_tests.rs"]
+mod tectonics_tests;
+use std::collections::VecDeque;
+use crate::{
+    octo::Octo,
+    rng::Rng,
+    sphere::{
+        cross,
+        dot,
+        normalize,
+        Vec3
+    }
+};
+#[derive(Clone,Debug,PartialEq)]
+pub struct PlateConfig {
+    pub seed: u64,
+    pub plate_count: usize,
+    pub max_speed: f64,
+    pub uplift: f64,
+    pub falloff: usize
+}
+impl Default for PlateConfig {
+    fn default() -> Self {
+        PlateConfig {
+            seed: 0,
+            plate_count: 12,
+            max_speed: 1.0,
+            uplift: 0.5,
+            falloff: 4
+        }
+    }
+}
+#[derive(Clone,Debug,PartialEq)]
+pub struct Plate {
+    pub seed_tile: usize,
+    pub axis: Vec3,
+    pub speed: f64
+}
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum BoundaryKind {
+    Convergent,
+    Divergent,
+    Transform
+}
+#[derive(Clone,Debug,PartialEq)]
+pub struct Boundary {
+    pub tile_id: usize,
+    pub neighbour: usize,
+    pub kind: BoundaryKind,
+    // Closing speed across the boundary, negative when the plates separate.
+    pub pressure: f64
+}
+#[derive(Clone,Debug,PartialEq)]
+pub struct Tectonics {
+    pub plates: Vec<Plate>,
+    pub plate_id: Vec<usize>,
+    pub boundaries: Vec<Boundary>
+}
+impl Tectonics {
+    // Grows plates from random seed tiles by picking a random frontier tile at
+    // each step, which gives ragged borders instead of hop distance circles.
+    pub fn generate(octo: &Octo, config: &PlateConfig) -> Tectonics {
+        let mut rng = Rng::new(config.seed);
+        let mut tiles: Vec<usize> = (0..octo.tile_count()).collect();
+        rng.shuffle(&mut tiles);
+        let plate_count = config.plate_count.max(1).min(tiles.len());
+        let plates: Vec<Plate> = tiles[..plate_count].iter().map(|&seed_tile| Plate {
+            seed_tile,
+            axis: rng.unit_vector(),
+            speed: rng.next_f64() * config.max_speed
+        }).collect();
+        let mut plate_id = vec![usize::MAX; octo.tile_count()];
+        let mut frontier = Vec::new();
+        for (p, plate) in plates.iter().enumerate() {
+            plate_id[plate.seed_tile] = p;
+            frontier.push(plate.seed_tile);
+        }
+        while !frontier.is_empty() {
+            let i = rng.below(frontier.len());
+            let tile_id = frontier[i];
+            let open: Vec<usize> = octo.neighbours(tile_id).iter()
+                .cloned()
+                .filter(|n| plate_id[*n] == usize::MAX)
+                .collect();
+            match open.len() {
+                0 => {
+                    frontier.swap_remove(i);
+                },
+                _ => {
+                    let n = open[rng.below(open.len())];
+                    plate_id[n] = plate_id[tile_id];
+                    frontier.push(n);
+                }
+            }
+        }
+        let mut tectonics = Tectonics {
+            plates,
+            plate_id,
+            boundaries: Vec::new()
+        };
+        tectonics.boundaries = tectonics.classify(octo);
+        tectonics
+    }
+    // Surface velocity of the plate carrying a tile, the cross product of its
+    // angular velocity and the tile centroid.
+    pub fn velocity(&self, octo: &Octo, tile_id: usize) -> Vec3 {
+        let plate = &self.plates[self.plate_id[tile_id]];
+        let omega = [plate.axis[0] * plate.speed, plate.axis[1] * plate.speed, plate.axis[2] * plate.speed];
+        cross(omega, octo.centroid(tile_id))
+    }
+    fn classify(&self, octo: &Octo) -> Vec<Boundary> {
+        let mut boundaries = Vec::new();
+        for tile_id in 0..octo.tile_count() {
+            for &neighbour in octo.neighbours(tile_id).iter() {
+                if self.plate_id[tile_id] == self.plate_id[neighbour] {
+                    continue;
+                }
+                let (a, b) = (octo.centroid(tile_id), octo.centroid(neighbour));
+                let normal = normalize([b[0] - a[0], b[1] - a[1], b[2] - a[2]]);
+                let (va, vb) = (self.velocity(octo, tile_id), self.velocity(octo, neighbour));
+                let relative = [va[0] - vb[0], va[1] - vb[1], va[2] - vb[2]];
+                let pressure = dot(relative, normal);
+                let shear = (dot(relative, relative) - pressure * pressure).max(0.0).sqrt();
+                boundaries.push(Boundary {
+                    tile_id,
+                    neighbour,
+                    kind: match pressure {
+                        _ if shear > pressure.abs() => BoundaryKind::Transform,
+                        _ if pressure > 0.0 => BoundaryKind::Convergent,
+                        _ => BoundaryKind::Divergent
+                    },
+                    pressure
+                });
+            }
+        }
+        boundaries
+    }
+    // Raises mountains along convergent boundaries and cuts trenches along
+    // divergent ones, fading linearly over config.falloff steps into each plate.
+    pub fn apply(&self, octo: &Octo, elevation: &mut [f64], config: &PlateConfig) {
+        let mut stress = vec![0.0; octo.tile_count()];
+        let mut distance = vec![usize::MAX; octo.tile_count()];
+        let mut queue = VecDeque::new();
+        for boundary in self.boundaries.iter().filter(|b| b.kind != BoundaryKind::Transform) {
+            stress[boundary.tile_id] += boundary.pressure;
+            if distance[boundary.tile_id] != 0 {
+                distance[boundary.tile_id] = 0;
+                queue.push_back(boundary.tile_id);
+            }
+        }
+        while let Some(tile_id) = queue.pop_front() {
+            if distance[tile_id] >= config.falloff {
+                continue;
+            }
+            for &n in octo.neighbours(tile_id).iter() {
+                if distance[n] == usize::MAX && self.plate_id[n] == self.plate_id[tile_id] {
+                    distance[n] = distance[tile_id] + 1;
+                    stress[n] = stress[tile_id];
+                    queue.push_back(n);
+                }
+            }
+        }
+        for tile_id in 0..octo.tile_count() {
+            if distance[tile_id] <= config.falloff {
+                let fade = 1.0 - distance[tile_id] as f64 / (config.falloff + 1) as f64;
+                elevation[tile_id] += config.uplift * stress[tile_id] * fade;
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,78 @@
+#[cfg(test)]
+use crate::{
+    octo::Octo,
+    tectonics::{
+        BoundaryKind,
+        PlateConfig,
+        Tectonics
+    }
+};
+#[test]
+fn test_plates_cover_and_are_connected() -> Result <(),String> {
+    for size in [1, 3, 12].iter() {
+        let octo = Octo::new(*size);
+        let config = PlateConfig {
+            seed: 11,
+            plate_count: 9,
+            ..PlateConfig::default()
+        };
+        let tectonics = Tectonics::generate(&octo, &config);
+        assert_eq!(tectonics, Tectonics::generate(&octo, &config));
+        assert_eq!(tectonics.plates.len(), 9.min(octo.tile_count()));
+        for (p, plate) in tectonics.plates.iter().enumerate() {
+            let mut seen = vec![false; octo.tile_count()];
+            let mut stack = vec![plate.seed_tile];
+            seen[plate.seed_tile] = true;
+            while let Some(t) = stack.pop() {
+                for n in octo.neighbours(t).iter() {
+                    if !seen[*n] && tectonics.plate_id[*n] == p {
+                        seen[*n] = true;
+                        stack.push(*n);
+                    }
+                }
+            }
+            for (i, s) in seen.iter().enumerate() {
+                assert_eq!(*s, tectonics.plate_id[i] == p);
+            }
+        }
+    }
+    Ok(())
+}
+#[test]
+fn test_boundaries_raise_and_lower() -> Result <(),String> {
+    let octo = Octo::new(16);
+    let config = PlateConfig {
+        seed: 5,
+        ..PlateConfig::default()
+    };
+    let tectonics = Tectonics::generate(&octo, &config);
+    assert!(!tectonics.boundaries.is_empty());
+    for boundary in tectonics.boundaries.iter() {
+        assert!(tectonics.plate_id[boundary.tile_id] != tectonics.plate_id[boundary.neighbour]);
+        match boundary.kind {
+            BoundaryKind::Convergent => assert!(boundary.pressure > 0.0),
+            BoundaryKind::Divergent => assert!(boundary.pressure < 0.0),
+            BoundaryKind::Transform => {}
+        }
+    }
+    let mut elevation = vec![0.0; octo.tile_count()];
+    tectonics.apply(&octo, &mut elevation, &config);
+    let convergent = tectonics.boundaries.iter().find(|b| b.kind == BoundaryKind::Convergent)
+        .ok_or("no convergent boundary")?;
+    assert!(elevation[convergent.tile_id] > 0.0);
+    // A tile on a moving boundary shifts by the full uplift times its summed
+    // pressure.
+    for boundary in tectonics.boundaries.iter().filter(|b| b.kind != BoundaryKind::Transform) {
+        let pressure: f64 = tectonics.boundaries.iter()
+            .filter(|b| b.tile_id == boundary.tile_id && b.kind != BoundaryKind::Transform)
+            .map(|b| b.pressure)
+            .sum();
+        assert!((elevation[boundary.tile_id] - config.uplift * pressure).abs() < 1e-12);
+    }
+    let rift = tectonics.boundaries.iter()
+        .find(|b| tectonics.boundaries.iter().filter(|c| c.tile_id == b.tile_id).all(|c| c.kind == BoundaryKind::Divergent))
+        .ok_or("no purely divergent boundary tile")?;
+    assert!(elevation[rift.tile_id] < 0.0);
+    assert!(elevation.contains(&0.0));
+    Ok(())
+}
\ No newline at end of file
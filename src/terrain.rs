@@ -0,0 +1,68 @@
+#[cfg(test)]
+#[path = "./terrain_tests.rs"]
+mod terrain_tests;
+use crate::{
+    noise::Noise,
+    octo::Octo
+};
+#[derive(Clone,Debug,PartialEq)]
+pub struct TerrainConfig {
+    pub seed: u64,
+    pub octaves: usize,
+    pub frequency: f64,
+    pub lacunarity: f64,
+    pub persistence: f64,
+    pub sea_level: f64,
+    pub continent_fraction: f64
+}
+impl Default for TerrainConfig {
+    fn default() -> Self {
+        TerrainConfig {
+            seed: 0,
+            octaves: 6,
+            frequency: 1.5,
+            lacunarity: 2.0,
+            persistence: 0.5,
+            sea_level: 0.0,
+            continent_fraction: 0.3
+        }
+    }
+}
+#[derive(Clone,Debug,PartialEq)]
+pub struct Terrain {
+    pub elevation: Vec<f64>,
+    pub sea_level: f64
+}
+impl Terrain {
+    // Samples fractal noise at every tile centroid, then shifts the field so
+    // that continent_fraction of the tiles lie above sea_level.
+    pub fn generate(octo: &Octo, config: &TerrainConfig) -> Terrain {
+        let noise = Noise::new(config.seed);
+        let raw: Vec<f64> = (0..octo.tile_count())
+            .map(|i| noise.fractal(octo.centroid(i), config.octaves, config.frequency, config.lacunarity, config.persistence))
+            .collect();
+        let mut sorted = raw.clone();
+        sorted.sort_by(f64::total_cmp);
+        let fraction = config.continent_fraction.clamp(0.0, 1.0);
+        let ocean = ((1.0 - fraction) * sorted.len() as f64).round() as usize;
+        let threshold = match ocean {
+            _ if sorted.is_empty() => 0.0,
+            0 => sorted[0] - 1.0,
+            _ if ocean >= sorted.len() => sorted[sorted.len() - 1] + 1.0,
+            _ => (sorted[ocean - 1] + sorted[ocean]) / 2.0
+        };
+        Terrain {
+            elevation: raw.iter().map(|e| e - threshold + config.sea_level).collect(),
+            sea_level: config.sea_level
+        }
+    }
+    pub fn is_land(&self, tile_id: usize) -> bool {
+        self.elevation[tile_id] > self.sea_level
+    }
+    pub fn land_fraction(&self) -> f64 {
+        match self.elevation.len() {
+            0 => 0.0,
+            n => self.elevation.iter().filter(|e| **e > self.sea_level).count() as f64 / n as f64
+        }
+    }
+}
\ No newline at end of file
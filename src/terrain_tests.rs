@@ -0,0 +1,65 @@
+#[cfg(test)]
+use crate::{
+    octo::Octo,
+    terrain::{
+        Terrain,
+        TerrainConfig
+    }
+};
+#[test]
+fn test_terrain_is_deterministic() -> Result <(),String> {
+    let octo = Octo::new(8);
+    let config = TerrainConfig {
+        seed: 42,
+        ..TerrainConfig::default()
+    };
+    assert_eq!(Terrain::generate(&octo, &config), Terrain::generate(&octo, &config));
+    assert!(Terrain::generate(&octo, &config) != Terrain::generate(&octo, &TerrainConfig {
+        seed: 43,
+        ..config.clone()
+    }));
+    Ok(())
+}
+#[test]
+fn test_terrain_continent_fraction() -> Result <(),String> {
+    let octo = Octo::new(10);
+    for fraction in [0.0, 0.25, 0.6, 1.0].iter() {
+        let terrain = Terrain::generate(&octo, &TerrainConfig {
+            seed: 7,
+            sea_level: 0.5,
+            continent_fraction: *fraction,
+            ..TerrainConfig::default()
+        });
+        assert!((terrain.land_fraction() - fraction).abs() < 1.0 / 800.0 + 1e-9);
+    }
+    Ok(())
+}
+#[test]
+fn test_terrain_has_no_seams() -> Result <(),String> {
+    let size = 24;
+    let octo = Octo::new(size);
+    let terrain = Terrain::generate(&octo, &TerrainConfig {
+        seed: 3,
+        octaves: 3,
+        ..TerrainConfig::default()
+    });
+    let face_base = size * size;
+    let (mut seam, mut seam_count, mut inner, mut inner_count) = (0.0, 0, 0.0, 0);
+    for i in 0..octo.tile_count() {
+        for n in octo.neighbours(i).iter() {
+            let difference = (terrain.elevation[i] - terrain.elevation[*n]).abs();
+            match i / face_base == n / face_base {
+                true => {
+                    inner += difference;
+                    inner_count += 1;
+                },
+                false => {
+                    seam += difference;
+                    seam_count += 1;
+                }
+            }
+        }
+    }
+    assert!(seam / (seam_count as f64) < 1.5 * inner / (inner_count as f64));
+    Ok(())
+}
\ No newline at end of file
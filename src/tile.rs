@@ -1,6 +1,12 @@
-use std::ops::{
-    Neg,
-    Not
+#[cfg(test)]
+#[path = "./tile_tests.rs"]
+mod tile_tests;
+use std::{
+    f64::consts::FRAC_PI_3,
+    ops::{
+        Neg,
+        Not
+    }
 };
 #[allow(dead_code)]
 #[derive(Clone,Debug,PartialEq)]
@@ -12,6 +18,87 @@ pub enum Direction {
     NegY,
     NegZ
 }
+// A heading relative to the current one, in sixth turns clockwise.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum Turn {
+    Ahead,
+    Right,
+    HardRight,
+    Back,
+    HardLeft,
+    Left
+}
+impl Turn {
+    pub fn sixths(self) -> usize {
+        match self {
+            Turn::Ahead => 0,
+            Turn::Right => 1,
+            Turn::HardRight => 2,
+            Turn::Back => 3,
+            Turn::HardLeft => 4,
+            Turn::Left => 5
+        }
+    }
+    pub fn opposite(self) -> Turn {
+        Turn::from_sixths(self.sixths() + 3)
+    }
+    pub fn from_sixths(sixths: usize) -> Turn {
+        match sixths % 6 {
+            0 => Turn::Ahead,
+            1 => Turn::Right,
+            2 => Turn::HardRight,
+            3 => Turn::Back,
+            4 => Turn::HardLeft,
+            _ => Turn::Left
+        }
+    }
+}
+// Seen from outside the sphere with PosX pointing north the directions of
+// every tile run clockwise in index order, 60 degrees apart, on both
+// hemispheres.
+impl Direction {
+    pub fn all() -> [Direction; 6] {
+        [Direction::PosX, Direction::PosY, Direction::PosZ, Direction::NegX, Direction::NegY, Direction::NegZ]
+    }
+    pub fn rotate_cw(&self) -> Direction {
+        Direction::from_index(self.index() + 1)
+    }
+    pub fn rotate_ccw(&self) -> Direction {
+        Direction::from_index(self.index() + 5)
+    }
+    pub fn turn(&self, turn: Turn) -> Direction {
+        Direction::from_index(self.index() + turn.sixths())
+    }
+    // The turn taking this heading onto other.
+    pub fn relative(&self, other: &Direction) -> Turn {
+        Turn::from_sixths(other.index() + 6 - self.index())
+    }
+    // Unsigned angle between two headings, in radians.
+    pub fn angle(&self, other: &Direction) -> f64 {
+        let sixths = (other.index() + 6 - self.index()) % 6;
+        sixths.min(6 - sixths) as f64 * FRAC_PI_3
+    }
+    pub fn index(&self) -> usize {
+        match self {
+            Direction::PosX => 0,
+            Direction::PosY => 1,
+            Direction::PosZ => 2,
+            Direction::NegX => 3,
+            Direction::NegY => 4,
+            Direction::NegZ => 5
+        }
+    }
+    pub fn from_index(index: usize) -> Direction {
+        match index % 6 {
+            0 => Direction::PosX,
+            1 => Direction::PosY,
+            2 => Direction::PosZ,
+            3 => Direction::NegX,
+            4 => Direction::NegY,
+            _ => Direction::NegZ
+        }
+    }
+}
 impl Neg for Direction {
     type Output = Self;
     fn neg(self) -> Self::Output {
@@ -0,0 +1,34 @@
+#[cfg(test)]
+use crate::tile::{
+    Direction,
+    Turn
+};
+#[test]
+fn test_rotation() -> Result <(),String> {
+    for d in Direction::all() {
+        assert_eq!(d.rotate_cw().rotate_ccw(), d);
+        assert_eq!(d.rotate_cw().rotate_cw().rotate_cw(), -d.clone());
+        assert_eq!((0..6).fold(d.clone(), |d, _| d.rotate_ccw()), d);
+        assert_eq!(d.turn(Turn::Right), d.rotate_cw());
+        assert_eq!(d.turn(Turn::Left), d.rotate_ccw());
+        assert_eq!(d.turn(Turn::Back), -d.clone());
+    }
+    assert_eq!(Direction::PosX.rotate_cw(), Direction::PosY);
+    assert_eq!(Direction::PosX.rotate_ccw(), Direction::NegZ);
+    Ok(())
+}
+#[test]
+fn test_relative_and_angle() -> Result <(),String> {
+    let third = std::f64::consts::FRAC_PI_3;
+    for a in Direction::all() {
+        for turn in (0..6).map(Turn::from_sixths) {
+            let b = a.turn(turn);
+            assert_eq!(a.relative(&b), turn);
+            assert_eq!(a.angle(&b), b.angle(&a));
+        }
+        assert_eq!(a.angle(&a), 0.0);
+        assert_eq!(a.angle(&a.turn(Turn::HardLeft)), 2.0 * third);
+        assert_eq!(a.angle(&-a.clone()), 3.0 * third);
+    }
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,98 @@
+// The octahedral square unfolding used for environment maps.
+// The flat octahedron |x| + |y| + |z| = 1 is pressed onto the square
+// [-1, 1]^2: the northern half keeps (x, y) and forms the inner diamond, the
+// southern faces fold out over its edges into the corners. Every face lands on
+// a right triangle and its tiles stay straight lattice triangles, so a square
+// image whose side is a multiple of 4 * face_size gives every tile whole
+// pixels, including the one under its centroid. Pixel rows run from v = 1 at
+// the top down to v = -1.
+#[cfg(test)]
+#[path = "./unfold_tests.rs"]
+mod unfold_tests;
+use crate::{
+    octo::Octo,
+    raster::Raster,
+    sphere::{
+        face_of,
+        planar_weights,
+        Vec3
+    }
+};
+// Square coordinates of a direction, it need not be normalized.
+pub fn square_encode(p: Vec3) -> (f64, f64) {
+    let l1 = p[0].abs() + p[1].abs() + p[2].abs();
+    let (x, y) = (p[0] / l1, p[1] / l1);
+    match p[2] < 0.0 {
+        true => ((1.0 - y.abs()) * x.signum(), (1.0 - x.abs()) * y.signum()),
+        false => (x, y)
+    }
+}
+// The point on the flat octahedron at square coordinates (u, v).
+pub fn square_decode(u: f64, v: f64) -> Vec3 {
+    let z = 1.0 - u.abs() - v.abs();
+    match z < 0.0 {
+        true => [(1.0 - v.abs()) * u.signum(), (1.0 - u.abs()) * v.signum(), z],
+        false => [u, v, z]
+    }
+}
+impl Octo {
+    pub fn tile_at_square(&self, u: f64, v: f64) -> usize {
+        let p = square_decode(u, v);
+        let face_id = face_of(p);
+        self.tile_at_weights(face_id, planar_weights(face_id, p))
+    }
+    pub fn square_position(&self, tile_id: usize) -> (f64, f64) {
+        let n = self.face_size() as f64;
+        let keys = self.vertex_keys(tile_id);
+        let mut p = [0.0; 3];
+        for (axis, value) in p.iter_mut().enumerate() {
+            *value = (keys[0][axis] + keys[1][axis] + keys[2][axis]) as f64 / (3.0 * n);
+        }
+        square_encode(p)
+    }
+    pub fn tile_at_pixel(&self, x: usize, y: usize, size: usize) -> usize {
+        self.tile_at_square(
+            2.0 * (x as f64 + 0.5) / size as f64 - 1.0,
+            1.0 - 2.0 * (y as f64 + 0.5) / size as f64
+        )
+    }
+    // The pixel holding the centroid of the tile in a square of the given side.
+    pub fn pixel_of(&self, tile_id: usize, size: usize) -> (usize, usize) {
+        let (u, v) = self.square_position(tile_id);
+        let pixel = |t: f64| ((t * size as f64) as usize).min(size - 1);
+        (pixel((u + 1.0) / 2.0), pixel((1.0 - v) / 2.0))
+    }
+}
+pub fn export_square<F>(octo: &Octo, size: usize, colour: F) -> Raster
+where F: Fn(usize) -> [u8; 3] {
+    let mut raster = Raster::new(size, size);
+    for y in 0..size {
+        for x in 0..size {
+            raster.set(x, y, colour(octo.tile_at_pixel(x, y, size)));
+        }
+    }
+    raster
+}
+// Averages square image samples, row major from the top left, into one value
+// per tile. The unfolding is piecewise linear so plain means are area
+// weighted, a tile smaller than a pixel takes the pixel under its centroid.
+pub fn import_square(octo: &Octo, size: usize, samples: &[f64]) -> Vec<f64> {
+    let mut sum = vec![0.0; octo.tile_count()];
+    let mut count = vec![0usize; octo.tile_count()];
+    for y in 0..size {
+        for x in 0..size {
+            let tile_id = octo.tile_at_pixel(x, y, size);
+            sum[tile_id] += samples[y * size + x];
+            count[tile_id] += 1;
+        }
+    }
+    (0..octo.tile_count())
+        .map(|t| match count[t] {
+            0 => {
+                let (x, y) = octo.pixel_of(t, size);
+                samples[y * size + x]
+            },
+            c => sum[t] / c as f64
+        })
+        .collect()
+}
\ No newline at end of file
@@ -0,0 +1,71 @@
+#[cfg(test)]
+use crate::{
+    octo::Octo,
+    unfold::{
+        export_square,
+        import_square,
+        square_decode,
+        square_encode
+    }
+};
+#[test]
+fn test_encode_round_trip() -> Result <(),String> {
+    let octo = Octo::new(5);
+    for t in 0..octo.tile_count() {
+        let p = octo.centroid(t);
+        let (u, v) = square_encode(p);
+        let q = square_decode(u, v);
+        let l1 = p[0].abs() + p[1].abs() + p[2].abs();
+        for axis in 0..3 {
+            assert!((q[axis] - p[axis] / l1).abs() < 1e-12, "tile {} decoded to {:?}", t, q);
+        }
+    }
+    assert_eq!(square_encode([0.0, 0.0, 1.0]), (0.0, 0.0));
+    assert_eq!(square_encode([1.0, 1.0, -2.0]), (0.75, 0.75));
+    Ok(())
+}
+#[test]
+fn test_pixel_round_trip() -> Result <(),String> {
+    for size in 1..8 {
+        let octo = Octo::new(size);
+        for pixels in [2 * size, 4 * size, 6 * size, 8 * size] {
+            for t in 0..octo.tile_count() {
+                let (x, y) = octo.pixel_of(t, pixels);
+                if pixels % (4 * size) == 0 {
+                    assert_eq!(octo.tile_at_pixel(x, y, pixels), t, "pixel ({}, {}) of {}", x, y, pixels);
+                }
+                let (u, v) = octo.square_position(t);
+                assert_eq!(octo.tile_at_square(u, v), t, "square ({}, {})", u, v);
+            }
+        }
+    }
+    Ok(())
+}
+#[test]
+fn test_faces_fill_square() -> Result <(),String> {
+    let octo = Octo::new(4);
+    let n2 = 16;
+    let size = 32;
+    let mut per_face = [0; 8];
+    for y in 0..size {
+        for x in 0..size {
+            per_face[octo.tile_at_pixel(x, y, size) / n2] += 1;
+        }
+    }
+    // Pixels centred on the equator fall to the northern faces.
+    assert_eq!(per_face, [136, 136, 136, 136, 120, 120, 120, 120]);
+    assert_eq!(octo.tile_at_pixel(size / 2, size / 2 - 1, size) / n2, 0);
+    assert_eq!(octo.tile_at_pixel(size - 1, 0, size) / n2, 7);
+    Ok(())
+}
+#[test]
+fn test_layer_round_trip() -> Result <(),String> {
+    let octo = Octo::new(6);
+    let values: Vec<f64> = (0..octo.tile_count()).map(|t| (t % 251) as f64).collect();
+    let raster = export_square(&octo, 24, |t| [values[t] as u8; 3]);
+    let samples: Vec<f64> = raster.pixels().iter().map(|p| p[0] as f64).collect();
+    assert_eq!(import_square(&octo, 24, &samples), values);
+    let coarse = import_square(&octo, 6, &vec![1.0; 36]);
+    assert!(coarse.iter().all(|v| *v == 1.0));
+    Ok(())
+}
\ No newline at end of file
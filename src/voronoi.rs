@@ -0,0 +1,196 @@
+#[cfg(test)]
+#[path = "./voronoi_tests.rs"]
+mod voronoi_tests;
+use std::{
+    cmp::Ordering,
+    collections::{
+        BinaryHeap,
+        VecDeque
+    }
+};
+use crate::{
+    octo::Octo,
+    sphere::{
+        arc,
+        Vec3
+    }
+};
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum Metric {
+    // Number of edge steps between tiles.
+    Hops,
+    // Angle between tile centroids, in radians.
+    GreatCircle
+}
+#[derive(Clone,Copy,Debug,PartialEq)]
+struct Entry {
+    cost: f64,
+    tile_id: usize,
+    seed: usize,
+    hops: usize
+}
+impl Eq for Entry {}
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.total_cmp(&self.cost).then(other.seed.cmp(&self.seed))
+    }
+}
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+#[derive(Clone,Debug,PartialEq)]
+pub struct Voronoi {
+    // Index into the seed list of the region each tile belongs to.
+    pub region: Vec<usize>,
+    // Weighted distance from each tile to its seed.
+    pub distance: Vec<f64>
+}
+impl Voronoi {
+    pub fn partition(octo: &Octo, seeds: &[usize], metric: Metric) -> Voronoi {
+        match metric {
+            Metric::Hops => Voronoi::partition_hops(octo, seeds),
+            Metric::GreatCircle => Voronoi::partition_weighted(octo, seeds, &vec![1.0; seeds.len()], metric)
+        }
+    }
+    // Multi-source breadth first search, linear in the number of tiles.
+    fn partition_hops(octo: &Octo, seeds: &[usize]) -> Voronoi {
+        let mut region = vec![usize::MAX; octo.tile_count()];
+        let mut distance = vec![f64::INFINITY; octo.tile_count()];
+        let mut queue = VecDeque::new();
+        for (s, &seed) in seeds.iter().enumerate() {
+            if region[seed] == usize::MAX {
+                region[seed] = s;
+                distance[seed] = 0.0;
+                queue.push_back(seed);
+            }
+        }
+        while let Some(tile_id) = queue.pop_front() {
+            for &n in octo.neighbours(tile_id).iter() {
+                if region[n] == usize::MAX {
+                    region[n] = region[tile_id];
+                    distance[n] = distance[tile_id] + 1.0;
+                    queue.push_back(n);
+                }
+            }
+        }
+        Voronoi {
+            region,
+            distance
+        }
+    }
+    // A tile's cost is its distance to the seed divided by the seed's weight,
+    // so heavier seeds claim larger regions.
+    pub fn partition_weighted(octo: &Octo, seeds: &[usize], weights: &[f64], metric: Metric) -> Voronoi {
+        assert_eq!(seeds.len(), weights.len(), "one weight per seed");
+        assert!(weights.iter().all(|w| w.is_finite() && *w > 0.0), "weights must be finite and positive");
+        match metric {
+            Metric::Hops => Voronoi::partition_weighted_hops(octo, seeds, weights),
+            Metric::GreatCircle => Voronoi::partition_great_circle(octo, seeds, weights)
+        }
+    }
+    // Multi-source Dijkstra, regions grow through neighbours and so always
+    // stay connected.
+    fn partition_weighted_hops(octo: &Octo, seeds: &[usize], weights: &[f64]) -> Voronoi {
+        let cost = |seed: usize, hops: usize| hops as f64 / weights[seed];
+        let mut region = vec![usize::MAX; octo.tile_count()];
+        let mut distance = vec![f64::INFINITY; octo.tile_count()];
+        let mut heap = BinaryHeap::new();
+        for (seed, &tile_id) in seeds.iter().enumerate() {
+            heap.push(Entry {
+                cost: 0.0,
+                tile_id,
+                seed,
+                hops: 0
+            });
+        }
+        while let Some(entry) = heap.pop() {
+            if region[entry.tile_id] != usize::MAX {
+                continue;
+            }
+            region[entry.tile_id] = entry.seed;
+            distance[entry.tile_id] = entry.cost;
+            for &n in octo.neighbours(entry.tile_id).iter() {
+                if region[n] == usize::MAX {
+                    heap.push(Entry {
+                        cost: cost(entry.seed, entry.hops + 1),
+                        tile_id: n,
+                        seed: entry.seed,
+                        hops: entry.hops + 1
+                    });
+                }
+            }
+        }
+        Voronoi {
+            region,
+            distance
+        }
+    }
+    // Exact nearest seed for every tile. Seeds are sorted by latitude, and
+    // since the arc between two points is at least their difference in
+    // latitude the scan outward from a tile's latitude stops once no
+    // remaining seed could beat the best found. Ties go to the lower seed.
+    // Still O(tiles * seeds) in the worst case, when the seeds share a narrow
+    // band of latitude or one weight dwarfs the rest so little is pruned.
+    fn partition_great_circle(octo: &Octo, seeds: &[usize], weights: &[f64]) -> Voronoi {
+        let centres: Vec<_> = seeds.iter().map(|s| octo.centroid(*s)).collect();
+        let latitude = |p: Vec3| p[2].clamp(-1.0, 1.0).asin();
+        let mut order: Vec<usize> = (0..seeds.len()).collect();
+        order.sort_by(|a, b| latitude(centres[*a]).total_cmp(&latitude(centres[*b])));
+        let latitudes: Vec<f64> = order.iter().map(|s| latitude(centres[*s])).collect();
+        let heaviest = weights.iter().cloned().fold(0.0, f64::max);
+        let mut region = vec![usize::MAX; octo.tile_count()];
+        let mut distance = vec![f64::INFINITY; octo.tile_count()];
+        for tile_id in 0..octo.tile_count() {
+            let p = octo.centroid(tile_id);
+            let lat = latitude(p);
+            let mut best = (f64::INFINITY, usize::MAX);
+            let mut consider = |seed: usize| {
+                let candidate = (arc(p, centres[seed]) / weights[seed], seed);
+                if candidate.0 < best.0 || (candidate.0 == best.0 && candidate.1 < best.1) {
+                    best = candidate;
+                }
+                best.0
+            };
+            let split = latitudes.partition_point(|l| *l < lat);
+            let (mut up, mut down) = (split, split);
+            let mut bound = f64::INFINITY;
+            loop {
+                let up_gap = latitudes.get(up).map(|l| (l - lat) / heaviest);
+                let down_gap = match down {
+                    0 => None,
+                    d => Some((lat - latitudes[d - 1]) / heaviest)
+                };
+                match (up_gap, down_gap) {
+                    (Some(u), Some(d)) if u <= d && u <= bound => {
+                        bound = consider(order[up]);
+                        up += 1;
+                    },
+                    (Some(u), None) if u <= bound => {
+                        bound = consider(order[up]);
+                        up += 1;
+                    },
+                    (_, Some(d)) if d <= bound => {
+                        bound = consider(order[down - 1]);
+                        down -= 1;
+                    },
+                    _ => break
+                }
+            }
+            region[tile_id] = best.1;
+            distance[tile_id] = best.0;
+        }
+        Voronoi {
+            region,
+            distance
+        }
+    }
+    pub fn region_sizes(&self, seed_count: usize) -> Vec<usize> {
+        let mut sizes = vec![0; seed_count];
+        for r in self.region.iter().filter(|r| **r < seed_count) {
+            sizes[*r] += 1;
+        }
+        sizes
+    }
+}
\ No newline at end of file
@@ -0,0 +1,86 @@
+#[cfg(test)]
+use crate::{
+    octo::Octo,
+    rng::Rng,
+    sphere::arc,
+    voronoi::{
+        Metric,
+        Voronoi
+    }
+};
+#[cfg(test)]
+fn hops_from(octo: &Octo, seed: usize) -> Vec<usize> {
+    let mut distance = vec![usize::MAX; octo.tile_count()];
+    let mut frontier = vec![seed];
+    distance[seed] = 0;
+    let mut d = 0;
+    while !frontier.is_empty() {
+        d += 1;
+        frontier = frontier.iter().flat_map(|t| octo.neighbours(*t).to_vec()).collect();
+        frontier.retain(|t| distance[*t] == usize::MAX);
+        frontier.sort_unstable();
+        frontier.dedup();
+        for t in frontier.iter() {
+            distance[*t] = d;
+        }
+    }
+    distance
+}
+#[test]
+fn test_hop_voronoi_is_nearest_seed() -> Result <(),String> {
+    let octo = Octo::new(9);
+    let mut rng = Rng::new(8);
+    let seeds: Vec<usize> = (0..7).map(|_| rng.below(octo.tile_count())).collect();
+    let voronoi = Voronoi::partition(&octo, &seeds, Metric::Hops);
+    let distances: Vec<Vec<usize>> = seeds.iter().map(|s| hops_from(&octo, *s)).collect();
+    for t in 0..octo.tile_count() {
+        let nearest = distances.iter().map(|d| d[t]).min().unwrap_or(0);
+        assert_eq!(voronoi.distance[t], nearest as f64);
+        assert_eq!(distances[voronoi.region[t]][t], nearest);
+    }
+    assert_eq!(voronoi.region_sizes(7).iter().sum::<usize>(), octo.tile_count());
+    let weighted = Voronoi::partition_weighted(&octo, &seeds, &[1.0; 7], Metric::Hops);
+    assert_eq!(weighted.distance, voronoi.distance);
+    Ok(())
+}
+#[test]
+fn test_great_circle_voronoi() -> Result <(),String> {
+    for (size, seed_count) in [(16, 10), (32, 40), (64, 200)] {
+        let octo = Octo::implicit(size);
+        let mut rng = Rng::new(31);
+        let seeds: Vec<usize> = (0..seed_count).map(|_| rng.below(octo.tile_count())).collect();
+        let voronoi = Voronoi::partition(&octo, &seeds, Metric::GreatCircle);
+        let mismatched = (0..octo.tile_count()).filter(|t| {
+            let nearest = seeds.iter().map(|s| arc(octo.centroid(*t), octo.centroid(*s))).fold(f64::INFINITY, f64::min);
+            voronoi.distance[*t] != nearest
+        }).count();
+        assert_eq!(mismatched, 0, "size {} with {} seeds", size, seed_count);
+        for seed in seeds.iter() {
+            assert_eq!(seeds[voronoi.region[*seed]], *seed);
+        }
+    }
+    Ok(())
+}
+#[test]
+fn test_weights_grow_regions() -> Result <(),String> {
+    let octo = Octo::new(12);
+    let seeds = [octo.tile_at(0.0, 0.3), octo.tile_at(0.0, 2.0)];
+    let even = Voronoi::partition_weighted(&octo, &seeds, &[1.0, 1.0], Metric::GreatCircle);
+    let heavy = Voronoi::partition_weighted(&octo, &seeds, &[2.0, 1.0], Metric::GreatCircle);
+    assert!(heavy.region_sizes(2)[0] > even.region_sizes(2)[0]);
+    let hops = Voronoi::partition_weighted(&octo, &seeds, &[1.0, 3.0], Metric::Hops);
+    assert!(hops.region_sizes(2)[1] > hops.region_sizes(2)[0]);
+    Ok(())
+}
+#[test]
+#[should_panic(expected = "weights must be finite and positive")]
+fn test_rejects_zero_weight() {
+    let octo = Octo::new(4);
+    Voronoi::partition_weighted(&octo, &[0, 50], &[1.0, 0.0], Metric::GreatCircle);
+}
+#[test]
+#[should_panic(expected = "weights must be finite and positive")]
+fn test_rejects_nan_weight() {
+    let octo = Octo::new(4);
+    Voronoi::partition_weighted(&octo, &[0, 50], &[f64::NAN, 1.0], Metric::Hops);
+}
\ No newline at end of file
@@ -0,0 +1,432 @@
+// On-disk format for a planet and its per-tile layers.
+// All integers are little endian. A file is a header followed by each layer
+// in turn:
+// header  magic       [u8; 4]   b"OCTW"
+//         version     u16       FORMAT_VERSION
+//         flags       u16       bit 0: layers carry a CRC-32
+//         face_size   u64
+//         layer_count u32
+// layer   name_len    u16
+//         name        [u8; name_len] (UTF-8)
+//         kind        u8        0 u8, 1 u16, 2 u32, 3 i32, 4 f32, 5 f64
+//         encoding    u8        0 raw, 1 run length
+//         length      u64       bytes of payload that follow
+//         payload     [u8; length]
+//         crc         u32       only if flag bit 0, over the decoded values
+// Every layer holds exactly one value per tile, 8 * face_size^2 of them, in
+// tile_id order, so a layer lines up with the faces built by Octo::new.
+// Run length payloads are a sequence of (count: u32, value) pairs.
+// Readers always upgrade to the current version in memory and writers only
+// emit the current version, so resaving an old file migrates it.
+#[cfg(test)]
+#[path = "./world_tests.rs"]
+mod world_tests;
+use std::{
+    convert::TryFrom,
+    error::Error,
+    fmt,
+    fs::File,
+    io::{
+        self,
+        BufReader,
+        BufWriter,
+        Read,
+        Write
+    },
+    path::Path
+};
+pub const MAGIC: [u8; 4] = *b"OCTW";
+pub const FORMAT_VERSION: u16 = 1;
+pub(crate) const FLAG_CHECKSUM: u16 = 1;
+pub(crate) const ENCODING_RAW: u8 = 0;
+pub(crate) const ENCODING_RUN_LENGTH: u8 = 1;
+#[derive(Debug)]
+pub enum WorldError {
+    Io(io::Error),
+    BadMagic,
+    UnsupportedVersion(u16),
+    UnknownKind(u8),
+    UnknownEncoding(u8),
+    BadName,
+    Malformed(String),
+    MissingLayer(String),
+    KindMismatch {
+        layer: String,
+        expected: LayerKind,
+        found: LayerKind
+    },
+    NotRaw(String),
+    LengthMismatch {
+        layer: String,
+        expected: usize,
+        found: usize
+    },
+    DuplicateLayer(String),
+    ChecksumMismatch(String)
+}
+impl fmt::Display for WorldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WorldError::Io(e) => write!(f, "io error: {}", e),
+            WorldError::BadMagic => write!(f, "not a world file"),
+            WorldError::UnsupportedVersion(v) => write!(f, "unsupported world file version {}", v),
+            WorldError::UnknownKind(k) => write!(f, "unknown layer kind {}", k),
+            WorldError::UnknownEncoding(e) => write!(f, "unknown layer encoding {}", e),
+            WorldError::BadName => write!(f, "layer name is not valid UTF-8"),
+            WorldError::Malformed(s) => write!(f, "malformed world file: {}", s),
+            WorldError::MissingLayer(name) => write!(f, "no layer named {}", name),
+            WorldError::KindMismatch { layer, expected, found } =>
+                write!(f, "layer {} holds {:?} values, not {:?}", layer, found, expected),
+            WorldError::NotRaw(name) => write!(f, "layer {} is compressed and cannot be mapped", name),
+            WorldError::LengthMismatch { layer, expected, found } =>
+                write!(f, "layer {} has {} tiles but face size needs {}", layer, found, expected),
+            WorldError::DuplicateLayer(name) => write!(f, "duplicate layer {}", name),
+            WorldError::ChecksumMismatch(name) => write!(f, "checksum mismatch in layer {}", name)
+        }
+    }
+}
+impl Error for WorldError {}
+impl From<io::Error> for WorldError {
+    fn from(e: io::Error) -> Self {
+        WorldError::Io(e)
+    }
+}
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum Compression {
+    None,
+    RunLength
+}
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub struct WriteOptions {
+    pub compression: Compression,
+    pub checksums: bool
+}
+impl Default for WriteOptions {
+    fn default() -> Self {
+        WriteOptions {
+            compression: Compression::None,
+            checksums: true
+        }
+    }
+}
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum LayerKind {
+    U8,
+    U16,
+    U32,
+    I32,
+    F32,
+    F64
+}
+impl LayerKind {
+    pub fn width(self) -> usize {
+        match self {
+            LayerKind::U8 => 1,
+            LayerKind::U16 => 2,
+            LayerKind::U32 | LayerKind::I32 | LayerKind::F32 => 4,
+            LayerKind::F64 => 8
+        }
+    }
+    pub(crate) fn code(self) -> u8 {
+        match self {
+            LayerKind::U8 => 0,
+            LayerKind::U16 => 1,
+            LayerKind::U32 => 2,
+            LayerKind::I32 => 3,
+            LayerKind::F32 => 4,
+            LayerKind::F64 => 5
+        }
+    }
+    pub(crate) fn from_code(code: u8) -> Result<LayerKind, WorldError> {
+        match code {
+            0 => Ok(LayerKind::U8),
+            1 => Ok(LayerKind::U16),
+            2 => Ok(LayerKind::U32),
+            3 => Ok(LayerKind::I32),
+            4 => Ok(LayerKind::F32),
+            5 => Ok(LayerKind::F64),
+            _ => Err(WorldError::UnknownKind(code))
+        }
+    }
+}
+#[derive(Clone,Debug,PartialEq)]
+pub enum LayerData {
+    U8(Vec<u8>),
+    U16(Vec<u16>),
+    U32(Vec<u32>),
+    I32(Vec<i32>),
+    F32(Vec<f32>),
+    F64(Vec<f64>)
+}
+impl LayerData {
+    pub fn len(&self) -> usize {
+        match self {
+            LayerData::U8(v) => v.len(),
+            LayerData::U16(v) => v.len(),
+            LayerData::U32(v) => v.len(),
+            LayerData::I32(v) => v.len(),
+            LayerData::F32(v) => v.len(),
+            LayerData::F64(v) => v.len()
+        }
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn kind(&self) -> LayerKind {
+        match self {
+            LayerData::U8(_) => LayerKind::U8,
+            LayerData::U16(_) => LayerKind::U16,
+            LayerData::U32(_) => LayerKind::U32,
+            LayerData::I32(_) => LayerKind::I32,
+            LayerData::F32(_) => LayerKind::F32,
+            LayerData::F64(_) => LayerKind::F64
+        }
+    }
+    fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            LayerData::U8(v) => v.clone(),
+            LayerData::U16(v) => v.iter().flat_map(|x| x.to_le_bytes().to_vec()).collect(),
+            LayerData::U32(v) => v.iter().flat_map(|x| x.to_le_bytes().to_vec()).collect(),
+            LayerData::I32(v) => v.iter().flat_map(|x| x.to_le_bytes().to_vec()).collect(),
+            LayerData::F32(v) => v.iter().flat_map(|x| x.to_le_bytes().to_vec()).collect(),
+            LayerData::F64(v) => v.iter().flat_map(|x| x.to_le_bytes().to_vec()).collect()
+        }
+    }
+    fn from_bytes(kind: LayerKind, bytes: &[u8]) -> Result<LayerData, WorldError> {
+        if !bytes.len().is_multiple_of(kind.width()) {
+            return Err(WorldError::Malformed("layer payload is not a whole number of values".to_string()));
+        }
+        let chunks = bytes.chunks_exact(kind.width());
+        Ok(match kind {
+            LayerKind::U8 => LayerData::U8(bytes.to_vec()),
+            LayerKind::U16 => LayerData::U16(chunks.map(|c| u16::from_le_bytes([c[0], c[1]])).collect()),
+            LayerKind::U32 => LayerData::U32(chunks.map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]])).collect()),
+            LayerKind::I32 => LayerData::I32(chunks.map(|c| i32::from_le_bytes([c[0], c[1], c[2], c[3]])).collect()),
+            LayerKind::F32 => LayerData::F32(chunks.map(|c| f32::from_le_bytes([c[0], c[1], c[2], c[3]])).collect()),
+            LayerKind::F64 => LayerData::F64(chunks.map(|c| {
+                let mut b = [0; 8];
+                b.copy_from_slice(c);
+                f64::from_le_bytes(b)
+            }).collect())
+        })
+    }
+}
+#[derive(Clone,Debug,PartialEq)]
+pub struct Layer {
+    pub name: String,
+    pub data: LayerData
+}
+#[derive(Clone,Debug,PartialEq)]
+pub struct World {
+    face_size: usize,
+    layers: Vec<Layer>
+}
+impl World {
+    pub fn new(face_size: usize) -> World {
+        World {
+            face_size,
+            layers: Vec::new()
+        }
+    }
+    pub fn face_size(&self) -> usize {
+        self.face_size
+    }
+    pub fn tile_count(&self) -> usize {
+        8 * usize::pow(self.face_size, 2)
+    }
+    pub fn layers(&self) -> &[Layer] {
+        &self.layers
+    }
+    pub fn layer(&self, name: &str) -> Option<&LayerData> {
+        self.layers.iter().find(|l| l.name == name).map(|l| &l.data)
+    }
+    pub fn add_layer(&mut self, name: &str, data: LayerData) -> Result<(), WorldError> {
+        if data.len() != self.tile_count() {
+            return Err(WorldError::LengthMismatch {
+                layer: name.to_string(),
+                expected: self.tile_count(),
+                found: data.len()
+            });
+        }
+        if self.layer(name).is_some() {
+            return Err(WorldError::DuplicateLayer(name.to_string()));
+        }
+        self.layers.push(Layer {
+            name: name.to_string(),
+            data
+        });
+        Ok(())
+    }
+    pub fn remove_layer(&mut self, name: &str) -> Option<LayerData> {
+        let i = self.layers.iter().position(|l| l.name == name)?;
+        Some(self.layers.remove(i).data)
+    }
+    pub fn write<W: Write>(&self, writer: &mut W, options: WriteOptions) -> Result<(), WorldError> {
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&FORMAT_VERSION.to_le_bytes())?;
+        let flags = match options.checksums {
+            true => FLAG_CHECKSUM,
+            false => 0
+        };
+        writer.write_all(&flags.to_le_bytes())?;
+        writer.write_all(&(self.face_size as u64).to_le_bytes())?;
+        writer.write_all(&(self.layers.len() as u32).to_le_bytes())?;
+        for layer in &self.layers {
+            let name = layer.name.as_bytes();
+            if name.len() > u16::MAX as usize {
+                return Err(WorldError::Malformed(format!("layer name {} is too long", layer.name)));
+            }
+            writer.write_all(&(name.len() as u16).to_le_bytes())?;
+            writer.write_all(name)?;
+            let kind = layer.data.kind();
+            let bytes = layer.data.to_bytes();
+            let (encoding, payload) = match options.compression {
+                Compression::None => (ENCODING_RAW, bytes.clone()),
+                Compression::RunLength => (ENCODING_RUN_LENGTH, rle_encode(&bytes, kind.width()))
+            };
+            writer.write_all(&[kind.code(), encoding])?;
+            writer.write_all(&(payload.len() as u64).to_le_bytes())?;
+            writer.write_all(&payload)?;
+            if options.checksums {
+                writer.write_all(&crc32(&bytes).to_le_bytes())?;
+            }
+        }
+        Ok(())
+    }
+    pub fn read<R: Read>(reader: &mut R) -> Result<World, WorldError> {
+        let mut magic = [0; 4];
+        reader.read_exact(&mut magic)?;
+        if magic != MAGIC {
+            return Err(WorldError::BadMagic);
+        }
+        match read_u16(reader)? {
+            1 => read_v1(reader),
+            v => Err(WorldError::UnsupportedVersion(v))
+        }
+    }
+    pub fn save<P: AsRef<Path>>(&self, path: P, options: WriteOptions) -> Result<(), WorldError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write(&mut writer, options)?;
+        writer.flush()?;
+        Ok(())
+    }
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<World, WorldError> {
+        World::read(&mut BufReader::new(File::open(path)?))
+    }
+}
+pub fn migrate<R: Read, W: Write>(reader: &mut R, writer: &mut W, options: WriteOptions) -> Result<(), WorldError> {
+    World::read(reader)?.write(writer, options)
+}
+fn read_v1<R: Read>(reader: &mut R) -> Result<World, WorldError> {
+    let flags = read_u16(reader)?;
+    let face_size = read_u64(reader)?;
+    checked_tile_count(face_size)?;
+    let face_size = face_size as usize;
+    let layer_count = read_u32(reader)?;
+    let mut world = World::new(face_size);
+    for _ in 0..layer_count {
+        let mut name = vec![0; read_u16(reader)? as usize];
+        reader.read_exact(&mut name)?;
+        let name = String::from_utf8(name).map_err(|_| WorldError::BadName)?;
+        let mut kind_encoding = [0; 2];
+        reader.read_exact(&mut kind_encoding)?;
+        let kind = LayerKind::from_code(kind_encoding[0])?;
+        let length = read_u64(reader)? as usize;
+        let mut payload = Vec::new();
+        reader.by_ref().take(length as u64).read_to_end(&mut payload)?;
+        if payload.len() != length {
+            return Err(WorldError::Malformed(format!("layer {} is truncated", name)));
+        }
+        let bytes = match kind_encoding[1] {
+            ENCODING_RAW => payload,
+            ENCODING_RUN_LENGTH => rle_decode(&payload, kind.width(), world.tile_count())?,
+            encoding => return Err(WorldError::UnknownEncoding(encoding))
+        };
+        if flags & FLAG_CHECKSUM != 0 && read_u32(reader)? != crc32(&bytes) {
+            return Err(WorldError::ChecksumMismatch(name));
+        }
+        world.add_layer(&name, LayerData::from_bytes(kind, &bytes)?)?;
+    }
+    Ok(world)
+}
+// Tiles for a face_size read from a file, refusing sizes whose layers could
+// not be addressed in memory even at the widest value kind.
+pub(crate) fn checked_tile_count(face_size: u64) -> Result<usize, WorldError> {
+    usize::try_from(face_size).ok()
+        .and_then(|n| n.checked_pow(2))
+        .and_then(|n| n.checked_mul(8))
+        .filter(|n| n.checked_mul(LayerKind::F64.width()).is_some())
+        .ok_or_else(|| WorldError::Malformed(format!("face size {} is too large", face_size)))
+}
+fn read_u16<R: Read>(reader: &mut R) -> io::Result<u16> {
+    let mut b = [0; 2];
+    reader.read_exact(&mut b)?;
+    Ok(u16::from_le_bytes(b))
+}
+fn read_u32<R: Read>(reader: &mut R) -> io::Result<u32> {
+    let mut b = [0; 4];
+    reader.read_exact(&mut b)?;
+    Ok(u32::from_le_bytes(b))
+}
+fn read_u64<R: Read>(reader: &mut R) -> io::Result<u64> {
+    let mut b = [0; 8];
+    reader.read_exact(&mut b)?;
+    Ok(u64::from_le_bytes(b))
+}
+fn rle_encode(bytes: &[u8], width: usize) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut values = bytes.chunks_exact(width).peekable();
+    while let Some(value) = values.next() {
+        let mut count: u32 = 1;
+        while count < u32::MAX && values.peek() == Some(&value) {
+            values.next();
+            count += 1;
+        }
+        out.extend_from_slice(&count.to_le_bytes());
+        out.extend_from_slice(value);
+    }
+    out
+}
+fn rle_decode(payload: &[u8], width: usize, tile_count: usize) -> Result<Vec<u8>, WorldError> {
+    let mut out = Vec::new();
+    let mut rest = payload;
+    while !rest.is_empty() {
+        if rest.len() < 4 + width {
+            return Err(WorldError::Malformed("truncated run".to_string()));
+        }
+        let count = u32::from_le_bytes([rest[0], rest[1], rest[2], rest[3]]) as usize;
+        if (out.len() / width) + count > tile_count {
+            return Err(WorldError::Malformed("runs exceed tile count".to_string()));
+        }
+        for _ in 0..count {
+            out.extend_from_slice(&rest[4..4 + width]);
+        }
+        rest = &rest[4 + width..];
+    }
+    Ok(out)
+}
+const CRC_TABLE: [u32; 256] = crc_table();
+const fn crc_table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut j = 0;
+        while j < 8 {
+            crc = match crc & 1 {
+                1 => (crc >> 1) ^ 0xEDB8_8320,
+                _ => crc >> 1
+            };
+            j += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+pub(crate) fn crc32(bytes: &[u8]) -> u32 {
+    !crc32_update(!0, bytes)
+}
+pub(crate) fn crc32_update(crc: u32, bytes: &[u8]) -> u32 {
+    bytes.iter().fold(crc, |crc, &b| (crc >> 8) ^ CRC_TABLE[((crc ^ b as u32) & 0xFF) as usize])
+}
\ No newline at end of file
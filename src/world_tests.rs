@@ -0,0 +1,83 @@
+#[cfg(test)]
+use crate::world::{
+    Compression,
+    LayerData,
+    World,
+    WorldError,
+    WriteOptions
+};
+#[cfg(test)]
+fn sample_world(face_size: usize) -> World {
+    let mut world = World::new(face_size);
+    let count = world.tile_count();
+    world.add_layer("plate", LayerData::U8((0..count).map(|i| (i / 7) as u8).collect())).unwrap();
+    world.add_layer("biome", LayerData::U16(vec![3; count])).unwrap();
+    world.add_layer("elevation", LayerData::F32((0..count).map(|i| i as f32 * 0.5 - 3.0).collect())).unwrap();
+    world.add_layer("depth", LayerData::F64((0..count).map(|i| -(i as f64)).collect())).unwrap();
+    world
+}
+#[test]
+fn test_round_trip() -> Result <(),String> {
+    for compression in [Compression::None, Compression::RunLength].iter() {
+        for checksums in [false, true].iter() {
+            let world = sample_world(4);
+            let mut bytes = Vec::new();
+            let options = WriteOptions {
+                compression: *compression,
+                checksums: *checksums
+            };
+            world.write(&mut bytes, options).map_err(|e| e.to_string())?;
+            assert_eq!(World::read(&mut bytes.as_slice()).map_err(|e| e.to_string())?, world);
+        }
+    }
+    Ok(())
+}
+#[test]
+fn test_run_length_shrinks_uniform_layer() -> Result <(),String> {
+    let mut world = World::new(16);
+    world.add_layer("ocean", LayerData::U32(vec![1; world.tile_count()])).map_err(|e| e.to_string())?;
+    let (mut raw, mut rle) = (Vec::new(), Vec::new());
+    world.write(&mut raw, WriteOptions::default()).map_err(|e| e.to_string())?;
+    world.write(&mut rle, WriteOptions {
+        compression: Compression::RunLength,
+        checksums: true
+    }).map_err(|e| e.to_string())?;
+    assert!(rle.len() < raw.len() / 10);
+    assert_eq!(World::read(&mut rle.as_slice()).map_err(|e| e.to_string())?, world);
+    Ok(())
+}
+#[test]
+fn test_rejects_mismatched_layer() -> Result <(),String> {
+    let mut world = World::new(3);
+    match world.add_layer("short", LayerData::U8(vec![0; 71])) {
+        Err(WorldError::LengthMismatch { expected: 72, found: 71, .. }) => {},
+        other => return Err(format!("{:?}", other))
+    }
+    let mut bytes = Vec::new();
+    sample_world(3).write(&mut bytes, WriteOptions::default()).map_err(|e| e.to_string())?;
+    bytes[8] = 4;
+    match World::read(&mut bytes.as_slice()) {
+        Err(WorldError::LengthMismatch { expected: 128, found: 72, .. }) => Ok(()),
+        other => Err(format!("{:?}", other))
+    }
+}
+#[test]
+fn test_rejects_corruption() -> Result <(),String> {
+    let mut bytes = Vec::new();
+    sample_world(2).write(&mut bytes, WriteOptions::default()).map_err(|e| e.to_string())?;
+    let mut flipped = bytes.clone();
+    let last = flipped.len() - 10;
+    flipped[last] ^= 0xFF;
+    assert!(matches!(World::read(&mut flipped.as_slice()), Err(WorldError::ChecksumMismatch(_))));
+    let mut future = bytes.clone();
+    future[4] = 99;
+    assert!(matches!(World::read(&mut future.as_slice()), Err(WorldError::UnsupportedVersion(99))));
+    assert!(matches!(World::read(&mut &b"PNG!"[..]), Err(WorldError::BadMagic)));
+    assert!(matches!(World::read(&mut &bytes[..bytes.len() - 3]), Err(WorldError::Io(_))));
+    for face_size in [1u64 << 40, 1 << 31, u64::MAX] {
+        let mut huge = bytes.clone();
+        huge[8..16].copy_from_slice(&face_size.to_le_bytes());
+        assert!(matches!(World::read(&mut huge.as_slice()), Err(WorldError::Malformed(_))));
+    }
+    Ok(())
+}
\ No newline at end of file